@@ -0,0 +1,212 @@
+use crate::movement::{Captured, Piece, PieceColor, PieceType};
+use crate::pieces::Dragged;
+use bevy::prelude::*;
+use std::f32::consts::PI;
+
+/// How high pieces are lifted before moving to their square
+const LIFT_HEIGHT: f32 = 0.5;
+/// How high knights jump over the other pieces
+const KNIGHT_ARC_HEIGHT: f32 = 1.2;
+/// How high taken pieces are thrown before falling in their tray
+const FALL_HEIGHT: f32 = 1.5;
+/// Share of a lift-and-place animation spent going up, and again coming down
+const LIFT_SHARE: f32 = 0.2;
+
+pub struct AnimationPlugin;
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AnimationSettings>()
+            .init_resource::<Animations>()
+            .add_event::<AnimationFinished>()
+            .add_systems(
+                Update,
+                (start_animations, animate_pieces, finish_animations).chain(),
+            );
+    }
+}
+
+/// How the progress of an animation speeds up and slows down.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Easing {
+    /// Speed up, then slow down to a stop
+    EaseInOut,
+    /// Start fast and slow down to a stop
+    EaseOut,
+}
+
+impl Easing {
+    fn apply(self, t: f32) -> f32 {
+        match self {
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4. * t * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(3) / 2.
+                }
+            }
+            Easing::EaseOut => 1. - (1. - t).powi(3),
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct AnimationSettings {
+    /// Time taken by a move, in seconds
+    pub duration: f32,
+    pub easing: Easing,
+}
+
+impl Default for AnimationSettings {
+    fn default() -> Self {
+        Self {
+            duration: 0.5,
+            easing: Easing::EaseInOut,
+        }
+    }
+}
+
+/// Number of pieces still moving, input waits until they are all on their square.
+#[derive(Resource, Default)]
+pub struct Animations {
+    running: usize,
+}
+
+impl Animations {
+    pub fn is_idle(&self) -> bool {
+        self.running == 0
+    }
+}
+
+/// Sent when a piece reaches the end of its animation.
+#[derive(Event)]
+pub struct AnimationFinished {
+    pub piece: Entity,
+}
+
+/// Shape of the path a piece follows.
+#[derive(Clone, Copy, PartialEq)]
+enum AnimationPath {
+    /// Go up, move over the board, and put the piece down
+    LiftAndPlace,
+    /// Jump over the pieces in the way
+    Arc,
+    /// Thrown off the board, falling in the tray of taken pieces
+    Fall,
+}
+
+#[derive(Component)]
+struct PieceAnimation {
+    start: Vec3,
+    end: Vec3,
+    path: AnimationPath,
+    easing: Easing,
+    duration: f32,
+    elapsed: f32,
+}
+
+impl PieceAnimation {
+    fn position(&self, t: f32) -> Vec3 {
+        let (progress, height) = match self.path {
+            AnimationPath::LiftAndPlace => {
+                let progress = ((t - LIFT_SHARE) / (1. - 2. * LIFT_SHARE)).clamp(0., 1.);
+                let height = (t.min(1. - t) / LIFT_SHARE).min(1.) * LIFT_HEIGHT;
+                (self.easing.apply(progress), height)
+            }
+            AnimationPath::Arc => (self.easing.apply(t), (t * PI).sin() * KNIGHT_ARC_HEIGHT),
+            AnimationPath::Fall => {
+                // Thrown up fast, then falling in a long drop
+                let rise = t.sqrt();
+                (
+                    Easing::EaseOut.apply(t),
+                    4. * rise * (1. - rise) * FALL_HEIGHT,
+                )
+            }
+        };
+        self.start.lerp(self.end, progress) + Vec3::Y * height
+    }
+}
+
+/// Where a taken piece is set aside, in a row beside the board for each color.
+fn tray_position(color: PieceColor, slot: u8) -> Vec3 {
+    let y = match color {
+        PieceColor::White => -1.5,
+        PieceColor::Black => 8.5,
+    };
+    Vec3::new(slot as f32 * 0.5, 0., y)
+}
+
+/// Where a piece rests when it is not moving.
+fn resting_position(piece: &Piece, captured: Option<&Captured>) -> Vec3 {
+    match captured {
+        Some(captured) => tray_position(piece.color, captured.slot),
+        None => Vec3::new(piece.x as f32, 0., piece.y as f32),
+    }
+}
+
+/// Animate the pieces that are not where they belong, after a move, a capture, a takeback or a
+/// drop on a square they can't go to.
+#[allow(clippy::type_complexity)]
+fn start_animations(
+    mut commands: Commands,
+    settings: Res<AnimationSettings>,
+    query: Query<
+        (
+            Entity,
+            &Transform,
+            &Piece,
+            Option<&Captured>,
+            Option<&PieceAnimation>,
+        ),
+        Without<Dragged>,
+    >,
+) {
+    for (entity, transform, piece, captured, animation) in query.iter() {
+        let end = resting_position(piece, captured);
+        let already_going = animation.is_some_and(|animation| animation.end == end);
+        if already_going || transform.translation.distance(end) < 0.01 {
+            continue;
+        }
+        let path = match (captured, piece.piece_type) {
+            (Some(_), _) => AnimationPath::Fall,
+            (None, PieceType::Knight) => AnimationPath::Arc,
+            (None, _) => AnimationPath::LiftAndPlace,
+        };
+        commands.entity(entity).insert(PieceAnimation {
+            start: transform.translation,
+            end,
+            path,
+            easing: settings.easing,
+            duration: settings.duration,
+            elapsed: 0.,
+        });
+    }
+}
+
+fn animate_pieces(
+    time: Res<Time>,
+    mut animations: ResMut<Animations>,
+    mut finished: EventWriter<AnimationFinished>,
+    mut query: Query<(Entity, &mut Transform, &mut PieceAnimation)>,
+) {
+    let mut running = 0;
+    for (entity, mut transform, mut animation) in query.iter_mut() {
+        animation.elapsed += time.delta_seconds();
+        let t = animation.elapsed / animation.duration;
+        if t >= 1. {
+            transform.translation = animation.end;
+            finished.send(AnimationFinished { piece: entity });
+        } else {
+            transform.translation = animation.position(t);
+            running += 1;
+        }
+    }
+    if animations.running != running {
+        animations.running = running;
+    }
+}
+
+fn finish_animations(mut commands: Commands, mut finished: EventReader<AnimationFinished>) {
+    for finished in finished.read() {
+        commands.entity(finished.piece).remove::<PieceAnimation>();
+    }
+}
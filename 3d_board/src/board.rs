@@ -1,5 +1,11 @@
-use crate::movement::{AttemptMove, Piece, PlayerTurn, Square};
-use bevy::math::vec4;
+use crate::animation::Animations;
+use crate::history::MoveHistory;
+use crate::movement::{
+    board, AttemptMove, Captured, Piece, PieceColor, PieceType, PlayerTurn, Square,
+};
+use crate::network::{can_play, OnlineGame};
+use crate::pieces::Dragged;
+use crate::theme::ThemeMaterials;
 use bevy::prelude::*;
 use bevy_mod_picking::prelude::*;
 
@@ -13,34 +19,36 @@ pub(crate) struct SelectedPiece {
     pub entity: Option<Entity>,
 }
 
-const HIGHLIGHT_TINT: Highlight<StandardMaterial> = Highlight {
-    hovered: Some(HighlightKind::new_dynamic(|matl| StandardMaterial {
-        base_color: matl.base_color + vec4(-0.2, -0.2, 0.4, 0.0),
-        ..matl.to_owned()
-    })),
-    pressed: Some(HighlightKind::new_dynamic(|matl| StandardMaterial {
-        base_color: matl.base_color + vec4(-0.3, -0.3, 0.5, 0.0),
-        ..matl.to_owned()
-    })),
-    selected: Some(HighlightKind::new_dynamic(|matl| StandardMaterial {
-        base_color: matl.base_color + vec4(-0.3, 0.2, -0.3, 0.0),
-        ..matl.to_owned()
-    })),
-};
+/// Color mixed into the squares the last move was played from and to
+const LAST_MOVE_TINT: Color = Color::rgb(0.9, 0.8, 0.1);
+const CHECK_COLOR: Color = Color::rgb(1., 0., 0.);
 
 pub struct BoardPlugin;
 impl Plugin for BoardPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SelectedSquare>()
             .init_resource::<SelectedPiece>()
-            .add_systems(Startup, create_board);
+            .add_systems(Startup, (create_board, create_check_indicator))
+            .add_systems(Update, (show_last_move, show_check));
     }
 }
 
+/// Material of a square, and whether it is a light or a dark square.
+#[derive(Component)]
+struct SquareMaterial {
+    handle: Handle<StandardMaterial>,
+    light: bool,
+}
+
+/// Ring put around the king in check.
+#[derive(Component)]
+struct CheckIndicator;
+
 fn create_board(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    theme: Res<ThemeMaterials>,
 ) {
     // Add meshes and materials
     let mesh = meshes.add(Mesh::from(shape::Plane {
@@ -51,22 +59,26 @@ fn create_board(
     // Spawn 64 squares
     for i in 0..8 {
         for j in 0..8 {
+            // Change material according to position to get alternating pattern
+            let light = (i + j + 1) % 2 == 0;
+            // Each square has its own material, to be tinted on its own
+            let material = materials.add(StandardMaterial::default());
             commands.spawn((
                 PbrBundle {
                     mesh: mesh.clone(),
-                    // Change material according to position to get alternating pattern
-                    material: if (i + j + 1) % 2 == 0 {
-                        materials.add(Color::rgb(1., 0.9, 0.9).into())
-                    } else {
-                        materials.add(Color::rgb(0., 0.1, 0.1).into())
-                    },
+                    material: material.clone(),
                     transform: Transform::from_translation(Vec3::new(i as f32, 0., j as f32)),
                     ..Default::default()
                 },
                 PickableBundle::default(),
-                HIGHLIGHT_TINT,
+                theme.square_highlight(light),
                 Square { x: i, y: j },
+                SquareMaterial {
+                    handle: material,
+                    light,
+                },
                 On::<Pointer<Select>>::run(select),
+                On::<Pointer<Drop>>::run(drop_piece),
             ));
         }
     }
@@ -78,10 +90,16 @@ fn select(
     mut selected_square: ResMut<SelectedSquare>,
     mut selected_piece: ResMut<SelectedPiece>,
     turn: Res<PlayerTurn>,
+    history: Res<MoveHistory>,
+    online: Option<Res<OnlineGame>>,
+    animations: Res<Animations>,
     mut attempt_moves: EventWriter<AttemptMove>,
     squares_query: Query<&Square>,
-    pieces_query: Query<(Entity, &mut Piece)>,
+    pieces_query: Query<(Entity, &mut Piece), Without<Captured>>,
 ) {
+    if !history.is_live() || !can_play(&turn, online.as_deref()) || !animations.is_idle() {
+        return;
+    }
     // Get the square under the cursor and set it as the selected
     selected_square.entity = Some(select.target);
     let Ok(square) = squares_query.get(select.target) else {
@@ -91,6 +109,7 @@ fn select(
         attempt_moves.send(AttemptMove {
             piece: selected_piece_entity,
             square: select.target,
+            promotion: None,
         });
         // Reset selection after attempting a move
         selected_square.entity = None;
@@ -105,3 +124,123 @@ fn select(
         });
     }
 }
+
+/// Move the dragged piece to the square it is dropped on
+fn drop_piece(
+    listener: Listener<Pointer<Drop>>,
+    mut selected_square: ResMut<SelectedSquare>,
+    mut selected_piece: ResMut<SelectedPiece>,
+    history: Res<MoveHistory>,
+    mut attempt_moves: EventWriter<AttemptMove>,
+    dragged_query: Query<Entity, With<Dragged>>,
+) {
+    let Ok(dragged) = dragged_query.get_single() else {
+        return;
+    };
+    if !history.is_live() {
+        return;
+    }
+    attempt_moves.send(AttemptMove {
+        piece: dragged,
+        square: listener.listener(),
+        promotion: None,
+    });
+    selected_square.entity = None;
+    selected_piece.entity = None;
+}
+
+/// Color the squares with the theme, tinting the ones the move leading to the position on the
+/// board was played from and to.
+fn show_last_move(
+    history: Res<MoveHistory>,
+    theme: Res<ThemeMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    squares_query: Query<(&Square, &SquareMaterial)>,
+) {
+    if !history.is_changed() && !theme.is_changed() {
+        return;
+    }
+    let last_move = history
+        .viewed_ply()
+        .checked_sub(1)
+        .and_then(|ply| history.records().get(ply))
+        .map(|record| record.done_move);
+    for (square, square_material) in squares_query.iter() {
+        let Some(material) = materials.get_mut(&square_material.handle) else {
+            continue;
+        };
+        let played = last_move.is_some_and(|last_move| {
+            (last_move.piece.x == square.x && last_move.piece.y == square.y)
+                || (last_move.square.x == square.x && last_move.square.y == square.y)
+        });
+        let color = if square_material.light {
+            theme.light_square
+        } else {
+            theme.dark_square
+        };
+        material.base_color = if played {
+            Vec4::from(color)
+                .lerp(Vec4::from(LAST_MOVE_TINT), 0.5)
+                .into()
+        } else {
+            color
+        };
+    }
+}
+
+fn create_check_indicator(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Torus {
+                radius: 0.4,
+                ring_radius: 0.04,
+                ..Default::default()
+            })),
+            material: materials.add(StandardMaterial {
+                base_color: CHECK_COLOR,
+                emissive: CHECK_COLOR,
+                unlit: true,
+                ..Default::default()
+            }),
+            visibility: Visibility::Hidden,
+            ..Default::default()
+        },
+        Pickable::IGNORE,
+        CheckIndicator,
+    ));
+}
+
+/// Put the ring around the king of the side to move when it is attacked.
+fn show_check(
+    history: Res<MoveHistory>,
+    mut query: Query<(&mut Transform, &mut Visibility), With<CheckIndicator>>,
+) {
+    if !history.is_changed() {
+        return;
+    }
+    let Ok((mut transform, mut visibility)) = query.get_single_mut() else {
+        return;
+    };
+    let ply = history.viewed_ply();
+    let pieces = history.position(ply);
+    // White moves first
+    let to_move = match ply % 2 {
+        0 => PieceColor::White,
+        _ => PieceColor::Black,
+    };
+    let in_check = board(&pieces).in_check(to_move.into());
+    let king_in_check = pieces
+        .iter()
+        .find(|piece| in_check && piece.piece_type == PieceType::King && piece.color == to_move);
+    *visibility = match king_in_check {
+        Some(king) => {
+            transform.translation = Vec3::new(king.x as f32, 0.02, king.y as f32);
+            Visibility::Inherited
+        }
+        None => Visibility::Hidden,
+    };
+}
@@ -0,0 +1,297 @@
+use crate::movement::{PieceColor, PlayerTurn};
+use crate::network::OnlineGame;
+use crate::settings::Settings;
+use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
+use bevy::input::touch::Touch;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::{FRAC_PI_2, PI};
+
+/// Point the camera orbits around, the middle of the board
+const BOARD_CENTER: Vec3 = Vec3::new(3.5, 0., 3.5);
+const DEFAULT_PITCH: f32 = 1.07;
+const DEFAULT_DISTANCE: f32 = 23.;
+const MIN_PITCH: f32 = 0.1;
+/// Just short of looking straight down, where the up direction of the camera is undefined
+const MAX_PITCH: f32 = FRAC_PI_2 - 0.01;
+const MIN_DISTANCE: f32 = 8.;
+const MAX_DISTANCE: f32 = 40.;
+/// Radians turned per pixel of mouse or finger drag
+const ORBIT_SPEED: f32 = 0.005;
+/// How fast the camera catches up with where it is going, higher is faster
+const EASING_RATE: f32 = 6.;
+
+pub struct CameraPlugin;
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraSettings>()
+            .add_event::<CameraView>()
+            .add_systems(Startup, spawn_camera)
+            .add_systems(
+                Update,
+                (
+                    camera_view_keys,
+                    follow_player,
+                    change_view,
+                    orbit_camera,
+                    touch_camera,
+                    ease_camera,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Where the camera looks at the board from.
+#[derive(Event, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CameraView {
+    /// Behind the white pieces
+    White,
+    /// Behind the black pieces
+    Black,
+    /// Straight above the board, from the side currently at the bottom
+    TopDown,
+    /// Level with the board, from beside it
+    Side,
+}
+
+#[derive(Resource)]
+pub struct CameraSettings {
+    /// Turn the board toward the side to move after each move, instead of keeping the view of the
+    /// local player
+    pub auto_flip: bool,
+    /// Last view picked, the board is only flipped when it is behind one side
+    pub view: CameraView,
+}
+
+impl FromWorld for CameraSettings {
+    fn from_world(world: &mut World) -> Self {
+        let settings = world
+            .get_resource::<Settings>()
+            .cloned()
+            .unwrap_or_default();
+        Self {
+            auto_flip: settings.auto_flip,
+            view: settings.camera_view,
+        }
+    }
+}
+
+/// Position of the camera around the board center.
+#[derive(Clone, Copy)]
+struct Orbit {
+    /// Angle around the board, 0 behind the white pieces
+    yaw: f32,
+    /// Angle above the board
+    pitch: f32,
+    distance: f32,
+}
+
+impl Orbit {
+    fn behind(color: PieceColor) -> Self {
+        Self {
+            yaw: match color {
+                PieceColor::White => 0.,
+                PieceColor::Black => PI,
+            },
+            pitch: DEFAULT_PITCH,
+            distance: DEFAULT_DISTANCE,
+        }
+    }
+
+    /// Orbit of a preset view, `yaw` being where the camera is now.
+    fn view(view: CameraView, yaw: f32) -> Self {
+        match view {
+            CameraView::White => Orbit::behind(PieceColor::White),
+            CameraView::Black => Orbit::behind(PieceColor::Black),
+            CameraView::TopDown => Orbit {
+                // Keep the side of the board at the bottom of the screen
+                yaw: if yaw.cos() >= 0. { 0. } else { PI },
+                pitch: MAX_PITCH,
+                distance: DEFAULT_DISTANCE,
+            },
+            CameraView::Side => Orbit {
+                yaw: FRAC_PI_2,
+                pitch: 0.4,
+                distance: DEFAULT_DISTANCE,
+            },
+        }
+    }
+
+    fn transform(&self) -> Transform {
+        let offset = Vec3::new(
+            -self.yaw.cos() * self.pitch.cos(),
+            self.pitch.sin(),
+            self.yaw.sin() * self.pitch.cos(),
+        ) * self.distance;
+        Transform::from_translation(BOARD_CENTER + offset).looking_at(BOARD_CENTER, Vec3::Y)
+    }
+}
+
+#[derive(Component)]
+pub struct MainCamera {
+    /// Where the camera is
+    current: Orbit,
+    /// Where the camera is easing to
+    target: Orbit,
+}
+
+impl MainCamera {
+    /// Turn around the board after a drag of `dragged` pixels. The camera follows right away,
+    /// easing would feel sluggish.
+    fn turn(&mut self, dragged: Vec2) {
+        let yaw = self.current.yaw + dragged.x * ORBIT_SPEED;
+        let pitch = (self.current.pitch + dragged.y * ORBIT_SPEED).clamp(MIN_PITCH, MAX_PITCH);
+        for orbit in [&mut self.current, &mut self.target] {
+            orbit.yaw = yaw;
+            orbit.pitch = pitch;
+        }
+    }
+
+    /// Scale the distance to the board, below 1 to get closer.
+    fn zoom(&mut self, factor: f32) {
+        self.target.distance = (self.target.distance * factor).clamp(MIN_DISTANCE, MAX_DISTANCE);
+    }
+}
+
+fn spawn_camera(mut commands: Commands, settings: Res<CameraSettings>) {
+    let orbit = Orbit::view(settings.view, 0.);
+    commands.spawn((
+        Camera3dBundle {
+            transform: orbit.transform(),
+            ..Default::default()
+        },
+        MainCamera {
+            current: orbit,
+            target: orbit,
+        },
+    ));
+}
+
+fn camera_view_keys(keys: Res<Input<KeyCode>>, mut views: EventWriter<CameraView>) {
+    if keys.just_pressed(KeyCode::F1) {
+        views.send(CameraView::White);
+    } else if keys.just_pressed(KeyCode::F2) {
+        views.send(CameraView::Black);
+    } else if keys.just_pressed(KeyCode::F3) {
+        views.send(CameraView::TopDown);
+    } else if keys.just_pressed(KeyCode::F4) {
+        views.send(CameraView::Side);
+    }
+}
+
+/// Face the side to move when the board flips, or the local player when it doesn't.
+fn follow_player(
+    settings: Res<CameraSettings>,
+    turn: Res<PlayerTurn>,
+    online: Option<Res<OnlineGame>>,
+    mut views: EventWriter<CameraView>,
+    mut last_auto_flip: Local<Option<bool>>,
+) {
+    // Views from above or from the side stay where they are
+    if !matches!(settings.view, CameraView::White | CameraView::Black) {
+        return;
+    }
+    let auto_flip_changed = *last_auto_flip != Some(settings.auto_flip);
+    *last_auto_flip = Some(settings.auto_flip);
+    let color = if settings.auto_flip {
+        if !turn.is_changed() && !auto_flip_changed {
+            return;
+        }
+        turn.color
+    } else {
+        let online_changed = online.as_ref().is_some_and(|online| online.is_changed());
+        if !online_changed && !auto_flip_changed {
+            return;
+        }
+        online
+            .and_then(|online| online.color)
+            .unwrap_or(PieceColor::White)
+    };
+    views.send(match color {
+        PieceColor::White => CameraView::White,
+        PieceColor::Black => CameraView::Black,
+    });
+}
+
+fn change_view(
+    mut settings: ResMut<CameraSettings>,
+    mut views: EventReader<CameraView>,
+    mut query: Query<&mut MainCamera>,
+) {
+    let Ok(mut camera) = query.get_single_mut() else {
+        return;
+    };
+    for view in views.read() {
+        camera.target = Orbit::view(*view, camera.target.yaw);
+        if settings.view != *view {
+            settings.view = *view;
+        }
+    }
+}
+
+/// Turn around the board while dragging with the right mouse button, zoom with the wheel.
+fn orbit_camera(
+    buttons: Res<Input<MouseButton>>,
+    mut motions: EventReader<MouseMotion>,
+    mut wheels: EventReader<MouseWheel>,
+    mut query: Query<&mut MainCamera>,
+) {
+    let Ok(mut camera) = query.get_single_mut() else {
+        return;
+    };
+    let dragged: Vec2 = motions.read().map(|motion| motion.delta).sum();
+    if buttons.pressed(MouseButton::Right) && dragged != Vec2::ZERO {
+        camera.turn(dragged);
+    }
+    for wheel in wheels.read() {
+        let lines = match wheel.unit {
+            MouseScrollUnit::Line => wheel.y,
+            MouseScrollUnit::Pixel => wheel.y / 40.,
+        };
+        camera.zoom(1. - lines * 0.1);
+    }
+}
+
+/// Turn around the board while dragging two fingers, zoom by pinching them. A single finger
+/// picks and drags the pieces.
+fn touch_camera(touches: Res<Touches>, mut query: Query<&mut MainCamera>) {
+    let Ok(mut camera) = query.get_single_mut() else {
+        return;
+    };
+    let fingers: Vec<&Touch> = touches.iter().collect();
+    let [first, second] = fingers[..] else {
+        return;
+    };
+    let dragged = (first.delta() + second.delta()) / 2.;
+    if dragged != Vec2::ZERO {
+        camera.turn(dragged);
+    }
+    let before = first
+        .previous_position()
+        .distance(second.previous_position());
+    let after = first.position().distance(second.position());
+    if before > 0. && after > 0. {
+        // Spreading the fingers brings the board closer
+        camera.zoom(before / after);
+    }
+}
+
+/// Move the camera toward its target, fast at first and slowing down as it gets there.
+fn ease_camera(time: Res<Time>, mut query: Query<(&mut Transform, &mut MainCamera)>) {
+    let Ok((mut transform, mut camera)) = query.get_single_mut() else {
+        return;
+    };
+    let step = 1. - (-EASING_RATE * time.delta_seconds()).exp();
+    let target = camera.target;
+    let current = &mut camera.current;
+    // Turn the short way around
+    let mut yaw_left = (target.yaw - current.yaw).rem_euclid(2. * PI);
+    if yaw_left > PI {
+        yaw_left -= 2. * PI;
+    }
+    current.yaw += yaw_left * step;
+    current.pitch += (target.pitch - current.pitch) * step;
+    current.distance += (target.distance - current.distance) * step;
+    *transform = current.transform();
+}
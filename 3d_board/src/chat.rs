@@ -0,0 +1,118 @@
+use crate::move_input::MoveInput;
+use crate::settings::Settings;
+use bevy::prelude::*;
+
+pub struct ChatPlugin;
+impl Plugin for ChatPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Chat>()
+            .add_event::<ChatReceived>()
+            .add_event::<SendChat>()
+            .add_systems(Update, (type_chat, receive_chat));
+    }
+}
+
+/// Who wrote a line of the chat.
+#[derive(Clone, PartialEq)]
+pub enum ChatAuthor {
+    /// The local player
+    Me,
+    Opponent(String),
+    /// A notice about the chat itself, like a message the server refused
+    Server,
+}
+
+#[derive(Clone)]
+pub struct ChatLine {
+    pub author: ChatAuthor,
+    pub text: String,
+}
+
+/// A line for the chat, coming from the server.
+#[derive(Event)]
+pub struct ChatReceived(pub ChatLine);
+
+/// A message of the local player, for the opponent.
+#[derive(Event)]
+pub struct SendChat(pub String);
+
+/// Messages of an online game, and the one being typed.
+#[derive(Resource, Default)]
+pub struct Chat {
+    pub lines: Vec<ChatLine>,
+    pub draft: String,
+    /// Whether the panel shows the messages or only its title
+    pub open: bool,
+    /// Whether the keyboard types in the chat instead of typing moves
+    pub typing: bool,
+    /// Name of the opponent, once they have written
+    pub opponent: Option<String>,
+    /// Messages received while the panel was closed
+    pub unread: usize,
+}
+
+impl Chat {
+    /// Whether messages from the opponent are hidden.
+    pub fn opponent_muted(&self, settings: &Settings) -> bool {
+        self.opponent
+            .as_ref()
+            .is_some_and(|opponent| settings.muted_users.contains(opponent))
+    }
+}
+
+fn type_chat(
+    keys: Res<Input<KeyCode>>,
+    mut characters: EventReader<ReceivedCharacter>,
+    mut chat: ResMut<Chat>,
+    mut input: ResMut<MoveInput>,
+    mut send: EventWriter<SendChat>,
+) {
+    if !chat.typing {
+        characters.clear();
+        return;
+    }
+    for character in characters.read() {
+        if !character.char.is_control() {
+            chat.draft.push(character.char);
+        }
+    }
+    if keys.just_pressed(KeyCode::Back) {
+        chat.draft.pop();
+    }
+    if keys.any_just_pressed([KeyCode::Return, KeyCode::NumpadEnter]) {
+        let text = std::mem::take(&mut chat.draft);
+        if !text.trim().is_empty() {
+            chat.lines.push(ChatLine {
+                author: ChatAuthor::Me,
+                text: text.clone(),
+            });
+            send.send(SendChat(text));
+        }
+    }
+    if keys.any_just_pressed([KeyCode::Return, KeyCode::NumpadEnter, KeyCode::Escape]) {
+        chat.typing = false;
+        input.suspended = false;
+    }
+}
+
+/// Add the received messages to the chat, leaving out those of muted players.
+fn receive_chat(
+    settings: Res<Settings>,
+    mut chat: ResMut<Chat>,
+    mut received: EventReader<ChatReceived>,
+) {
+    for ChatReceived(line) in received.read() {
+        if let ChatAuthor::Opponent(name) = &line.author {
+            if chat.opponent.as_ref() != Some(name) {
+                chat.opponent = Some(name.clone());
+            }
+            if settings.muted_users.contains(name) {
+                continue;
+            }
+        }
+        chat.lines.push(line.clone());
+        if !chat.open {
+            chat.unread += 1;
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::movement::{board, Move, Piece, PieceColor};
+use crate::notation;
+use crate::piece_set::{PieceSet, PieceSets};
+use crate::pieces::spawn_piece_model;
+use crate::theme::ThemeMaterials;
+use bevy::prelude::*;
+use fluffy_chess_protocol::rules::Board;
+
+pub struct HistoryPlugin;
+impl Plugin for HistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MoveHistory>()
+            .add_systems(PostStartup, record_initial_position)
+            .add_systems(
+                Update,
+                (record_moves, step_through_history, show_history_position).chain(),
+            );
+    }
+}
+
+/// A move that was played, along with its notation.
+pub struct MoveRecord {
+    pub done_move: Move,
+    pub san: String,
+}
+
+/// Every move of the current game, and the position being looked at.
+#[derive(Resource, Default)]
+pub struct MoveHistory {
+    initial: Vec<Piece>,
+    records: Vec<MoveRecord>,
+    /// Number of moves played in the position shown on the board, `None` to follow the game.
+    viewed: Option<usize>,
+}
+
+impl MoveHistory {
+    pub fn records(&self) -> &[MoveRecord] {
+        &self.records
+    }
+
+    /// Whether the board shows the position of the game being played.
+    pub fn is_live(&self) -> bool {
+        self.viewed.is_none()
+    }
+
+    /// Number of moves played in the position shown on the board.
+    pub fn viewed_ply(&self) -> usize {
+        self.viewed.unwrap_or(self.records.len())
+    }
+
+    /// Show the position after `ply` moves, going back to the live game after the last move.
+    pub fn view(&mut self, ply: usize) {
+        self.viewed = if ply < self.records.len() {
+            Some(ply)
+        } else {
+            None
+        };
+    }
+
+    /// Forget the last move after it was taken back, and go back to the live game.
+    pub fn take_back(&mut self) {
+        self.records.pop();
+        self.viewed = None;
+    }
+
+    /// Position of the pieces after `ply` moves.
+    pub fn position(&self, ply: usize) -> Vec<Piece> {
+        let mut pieces = self.initial.clone();
+        for record in self.records.iter().take(ply) {
+            record.done_move.apply(&mut pieces);
+        }
+        pieces
+    }
+
+    /// Position after `ply` moves, with the castling and en passant they allow.
+    pub fn board(&self, ply: usize) -> Board {
+        let mut board = board(&self.initial);
+        for color in [PieceColor::White, PieceColor::Black] {
+            for king_side in [true, false] {
+                board.allow_castling(color.into(), king_side);
+            }
+        }
+        for record in self.records.iter().take(ply) {
+            let done_move = record.done_move;
+            board.apply(
+                (done_move.piece.x, done_move.piece.y),
+                (done_move.square.x, done_move.square.y),
+                done_move.promotion.map(Into::into),
+            );
+        }
+        board
+    }
+}
+
+/// Marks the pieces shown in place of the live ones while looking at an earlier position.
+#[derive(Component)]
+struct HistoryPiece;
+
+fn record_initial_position(mut history: ResMut<MoveHistory>, pieces_query: Query<&Piece>) {
+    history.initial = pieces_query.iter().copied().collect();
+}
+
+pub(crate) fn record_moves(mut history: ResMut<MoveHistory>, mut moves: EventReader<Move>) {
+    for done_move in moves.read() {
+        let before = history.board(history.records.len());
+        let san = notation::san(done_move, &before);
+        history.records.push(MoveRecord {
+            done_move: *done_move,
+            san,
+        });
+    }
+}
+
+fn step_through_history(keys: Res<Input<KeyCode>>, mut history: ResMut<MoveHistory>) {
+    let ply = history.viewed_ply();
+    let last_ply = history.records.len();
+    if keys.just_pressed(KeyCode::Left) {
+        history.view(ply.saturating_sub(1));
+    } else if keys.just_pressed(KeyCode::Right) {
+        history.view(ply + 1);
+    } else if keys.just_pressed(KeyCode::Home) {
+        history.view(0);
+    } else if keys.just_pressed(KeyCode::End) {
+        history.view(last_ply);
+    }
+}
+
+/// Swap the live pieces for a read-only copy of the position being looked at.
+#[allow(clippy::too_many_arguments)]
+fn show_history_position(
+    mut commands: Commands,
+    history: Res<MoveHistory>,
+    piece_sets: Res<PieceSets>,
+    piece_set_assets: Res<Assets<PieceSet>>,
+    theme: Res<ThemeMaterials>,
+    mut live_query: Query<&mut Visibility, With<Piece>>,
+    history_query: Query<Entity, With<HistoryPiece>>,
+    mut shown_ply: Local<Option<usize>>,
+) {
+    if *shown_ply == history.viewed && !piece_sets.is_changed() {
+        return;
+    }
+    *shown_ply = history.viewed;
+
+    for entity in history_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    for mut visibility in live_query.iter_mut() {
+        *visibility = if history.is_live() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+    let piece_set = piece_set_assets.get(piece_sets.current_id());
+    if let (Some(ply), Some(piece_set)) = (history.viewed, piece_set) {
+        for piece in history.position(ply) {
+            let entity = spawn_piece_model(&mut commands, piece_set, &theme, &piece);
+            commands.entity(entity).insert(HistoryPiece);
+        }
+    }
+}
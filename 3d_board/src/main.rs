@@ -1,23 +1,47 @@
 use bevy::prelude::*;
-use bevy::time::Stopwatch;
-use bevy::window::WindowResolution;
 use bevy_mod_picking::prelude::*;
+#[cfg(not(target_arch = "wasm32"))]
+use clap::Parser;
 
 mod pieces;
 use pieces::*;
 
+mod animation;
 mod board;
 use board::*;
 
+mod camera;
+mod chat;
+mod history;
+mod move_input;
 mod movement;
+mod network;
+mod notation;
+mod piece_set;
+mod settings;
+mod sound;
+mod theme;
+mod transport;
 mod ui;
+mod undo;
 
+use crate::animation::AnimationPlugin;
+use crate::camera::CameraPlugin;
+use crate::chat::ChatPlugin;
+use crate::history::HistoryPlugin;
+use crate::move_input::MoveInputPlugin;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::network::FriendChange;
+use crate::network::{NetworkPlugin, ServerConfig};
+use crate::piece_set::PieceSetPlugin;
+use crate::settings::{Settings, SettingsPlugin};
+use crate::sound::SoundPlugin;
+use crate::theme::ThemePlugin;
 use crate::ui::UIPlugin;
+use crate::undo::UndoPlugin;
 use movement::*;
-
-mod fluffy_chess_capnp {
-    include!("../../proto/fluffy_chess_capnp.rs");
-}
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
 
 #[derive(States, Default, Debug, Clone, Eq, PartialEq, Hash)]
 enum AppState {
@@ -26,95 +50,201 @@ enum AppState {
     InGame,
 }
 
+/// 3D chess board, played locally or online
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Address of the game server to play online, instead of the one in the settings. The game is
+    /// local when there is none.
+    #[arg(long)]
+    server: Option<String>,
+    /// Encrypt the connection to the server with TLS
+    #[arg(long)]
+    tls: bool,
+    /// PEM file of a certificate to trust, for a server with a self-signed one. Implies --tls.
+    #[arg(long)]
+    trust_certificate: Option<PathBuf>,
+    /// Name to play as on the server, instead of the one in the settings
+    #[arg(long)]
+    user: Option<String>,
+    /// Watch the game with this ID on the server instead of playing
+    #[arg(long)]
+    watch: Option<u64>,
+    /// Print the games being played on the server, to pick one to watch
+    #[arg(long)]
+    list_games: bool,
+    /// Play a rated game, against an opponent of a close rating
+    #[arg(long)]
+    rated: bool,
+    /// Invite a user of the server to a game, they accept or decline it from their game
+    #[arg(long)]
+    challenge: Option<String>,
+    /// Print the friends of the user on the server, and who asks to become one
+    #[arg(long)]
+    friends: bool,
+    /// Ask a user of the server to become friends, or accept their request
+    #[arg(long)]
+    add_friend: Option<String>,
+    /// Stop being friends with a user of the server, or decline their request
+    #[arg(long)]
+    remove_friend: Option<String>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
 fn main() {
+    let args = Args::parse();
+    let settings = Settings::load();
+    let server = args
+        .server
+        .or_else(|| settings.server.clone())
+        .map(|address| ServerConfig {
+            address,
+            tls: args.tls || args.trust_certificate.is_some(),
+            trusted_certificate: args.trust_certificate,
+        });
+    let user = args.user.unwrap_or_else(|| settings.user.clone());
+
+    if args.list_games {
+        let Some(server) = server else {
+            println!("No server to list the games of, give one with --server");
+            return;
+        };
+        match network::list_games(&server) {
+            Ok(games) if games.is_empty() => println!("No game is being played"),
+            Ok(games) => {
+                for game in games {
+                    println!(
+                        "{}: {} - {}, {} moves played",
+                        game.id, game.white, game.black, game.ply
+                    );
+                }
+            }
+            Err(err) => println!("Could not list the games: {err}"),
+        }
+        return;
+    }
+
+    let change = match (args.add_friend, args.remove_friend) {
+        (Some(username), _) => Some(FriendChange::Add(username)),
+        (None, Some(username)) => Some(FriendChange::Remove(username)),
+        (None, None) => None,
+    };
+    if args.friends || change.is_some() {
+        let Some(server) = server else {
+            println!("No server to find friends on, give one with --server");
+            return;
+        };
+        match network::friends(&server, &user, change) {
+            Ok((friends, requests)) => {
+                if friends.is_empty() {
+                    println!("No friends yet");
+                }
+                for friend in friends {
+                    let presence = if friend.online { "online" } else { "offline" };
+                    println!("{}: {presence}", friend.username);
+                }
+                for username in requests {
+                    println!("{username} asks to become friends");
+                }
+            }
+            Err(err) => println!("Could not get the friends: {err}"),
+        }
+        return;
+    }
+
+    run(
+        settings,
+        NetworkPlugin {
+            server,
+            user,
+            watch: args.watch,
+            rated: args.rated,
+            challenge: args.challenge,
+        },
+    );
+}
+
+/// In a browser the options come from the query of the page address, like
+/// `?server=example.com:7172&user=name`. The connection is encrypted when the page is.
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    let settings = Settings::load();
+    let location = web_sys::window().map(|window| window.location());
+    let query = location
+        .as_ref()
+        .and_then(|location| location.search().ok())
+        .and_then(|search| web_sys::UrlSearchParams::new_with_str(&search).ok());
+    let param = |name: &str| query.as_ref().and_then(|query| query.get(name));
+    let secure = location
+        .and_then(|location| location.protocol().ok())
+        .is_some_and(|protocol| protocol == "https:");
+
+    let server = param("server")
+        .or_else(|| settings.server.clone())
+        .map(|address| ServerConfig {
+            address,
+            tls: secure,
+            trusted_certificate: None,
+        });
+    let network = NetworkPlugin {
+        server,
+        user: param("user").unwrap_or_else(|| settings.user.clone()),
+        watch: param("watch").and_then(|id| id.parse().ok()),
+        rated: param("rated").is_some(),
+        challenge: param("challenge"),
+    };
+    run(settings, network);
+}
+
+fn run(settings: Settings, network: NetworkPlugin) {
     App::new()
-        // Set antialiasing to use 4 samples
-        .insert_resource(Msaa::default())
+        .insert_resource(settings.msaa())
         .add_state::<AppState>()
         // Set WindowDescriptor Resource to change title and size
         .add_plugins((
             DefaultPlugins.set(WindowPlugin {
                 primary_window: Some(Window {
                     title: "Chess!".to_string(),
-                    resolution: WindowResolution::new(800., 800.),
+                    resolution: settings.window_resolution(),
+                    // Draw on the canvas of the page, at its size
+                    #[cfg(target_arch = "wasm32")]
+                    canvas: Some("#fluffy_chess".to_string()),
+                    #[cfg(target_arch = "wasm32")]
+                    fit_canvas_to_parent: true,
                     ..Default::default()
                 }),
                 ..Default::default()
             }),
             DefaultPickingPlugins,
+        ))
+        // Read by the plugins below when they create their resources
+        .insert_resource(settings)
+        .add_plugins((
+            SettingsPlugin,
+            ThemePlugin,
+            PieceSetPlugin,
+            CameraPlugin,
             BoardPlugin,
             PiecesPlugin,
+            AnimationPlugin,
             MovementPlugin,
+            HistoryPlugin,
+            UndoPlugin,
+            SoundPlugin,
+            MoveInputPlugin,
+            ChatPlugin,
+            network,
             UIPlugin,
         ))
         .add_systems(Startup, setup)
         .run();
 }
 
-#[derive(Component, Default)]
-struct MainCamera {
-    stopwatch: Stopwatch,
-}
-
-const CAMERA_ROT_WHITE: (Quat, Quat) = (
-    Quat::from_xyzw(-0.3, -0.5, -0.3, 0.5),
-    Quat::from_xyzw(0.3, 0.5, 0.3, -0.5),
-);
-const CAMERA_ROT_BLACK: (Quat, Quat) = (
-    Quat::from_xyzw(-0.3, 0.5, 0.3, 0.5),
-    Quat::from_xyzw(0.3, -0.5, -0.3, -0.5),
-);
-
 fn setup(mut commands: Commands) {
-    commands
-        // Camera
-        .spawn((
-            Camera3dBundle {
-                transform: Transform::from_matrix(Mat4::from_rotation_translation(
-                    CAMERA_ROT_WHITE.0.normalize(),
-                    Vec3::new(-7.0, 20.0, 4.0),
-                )),
-                ..Default::default()
-            },
-            MainCamera::default(),
-        ));
     // Light
     commands.spawn(PointLightBundle {
         transform: Transform::from_translation(Vec3::new(4.0, 8.0, 4.0)),
         ..Default::default()
     });
 }
-
-fn move_camera(
-    time: Res<Time>,
-    turn: Res<PlayerTurn>,
-    mut moves: EventReader<movement::Move>,
-    mut query: Query<(&mut Transform, &mut MainCamera)>,
-) {
-    let Ok((mut transform, mut camera)) = query.get_single_mut() else {
-        return;
-    };
-    if let Some(_) = moves.read().next() {
-        camera.stopwatch.reset();
-    } else {
-        camera.stopwatch.tick(time.delta());
-    }
-    // Get the direction to turn in
-    let target = match turn.color {
-        PieceColor::White => CAMERA_ROT_WHITE,
-        PieceColor::Black => CAMERA_ROT_BLACK,
-    };
-
-    if (target.0.normalize() - transform.rotation.normalize()).length() > 0.01
-        && (target.1.normalize() - transform.rotation.normalize()).length() > 0.01
-    {
-        transform.rotate_around(
-            Vec3 {
-                x: 4.,
-                y: 0.,
-                z: 4.,
-            },
-            Quat::from_rotation_y(180.0f32.to_radians() * time.delta_seconds()),
-        );
-    }
-}
@@ -0,0 +1,112 @@
+use crate::animation::Animations;
+use crate::history::MoveHistory;
+use crate::movement::{AttemptMove, Captured, Piece, PlayerTurn, Square};
+use crate::network::{can_play, OnlineGame};
+use crate::notation;
+use bevy::prelude::*;
+
+pub struct MoveInputPlugin;
+impl Plugin for MoveInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MoveInput>()
+            .add_systems(Update, (type_move, play_typed_move).chain());
+    }
+}
+
+/// Move being typed on the keyboard, and why the last one typed could not be played.
+#[derive(Resource, Default)]
+pub struct MoveInput {
+    pub text: String,
+    pub error: Option<String>,
+    /// Set while the keyboard fills in another text field
+    pub suspended: bool,
+    submitted: bool,
+}
+
+/// Characters that can appear in SAN or UCI notation
+fn is_notation_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '=' | '+' | '#' | ':' | '!' | '?')
+}
+
+fn type_move(
+    keys: Res<Input<KeyCode>>,
+    mut characters: EventReader<ReceivedCharacter>,
+    mut input: ResMut<MoveInput>,
+) {
+    // Keyboard shortcuts are not moves
+    if input.suspended || keys.pressed(KeyCode::ControlLeft) || keys.pressed(KeyCode::ControlRight)
+    {
+        characters.clear();
+        return;
+    }
+    for character in characters.read() {
+        if is_notation_char(character.char) {
+            input.text.push(character.char);
+            input.error = None;
+        }
+    }
+    if keys.just_pressed(KeyCode::Back) {
+        input.text.pop();
+    }
+    if keys.just_pressed(KeyCode::Escape) {
+        input.text.clear();
+        input.error = None;
+    }
+    if (keys.just_pressed(KeyCode::Return) || keys.just_pressed(KeyCode::NumpadEnter))
+        && !input.text.is_empty()
+    {
+        input.submitted = true;
+    }
+}
+
+/// Parse the typed move against the position on the board and play it.
+#[allow(clippy::too_many_arguments)]
+fn play_typed_move(
+    mut input: ResMut<MoveInput>,
+    turn: Res<PlayerTurn>,
+    history: Res<MoveHistory>,
+    online: Option<Res<OnlineGame>>,
+    animations: Res<Animations>,
+    mut attempt_moves: EventWriter<AttemptMove>,
+    pieces_query: Query<(Entity, &Piece), Without<Captured>>,
+    squares_query: Query<(Entity, &Square)>,
+) {
+    // Wait for the last move to be shown before playing the next one
+    if !input.submitted || !animations.is_idle() {
+        return;
+    }
+    input.submitted = false;
+
+    if !history.is_live() {
+        input.error = Some("go back to the current position to play".to_string());
+        return;
+    }
+    if !can_play(&turn, online.as_deref()) {
+        input.error = Some("it is not your turn".to_string());
+        return;
+    }
+    let pieces: Vec<Piece> = pieces_query.iter().map(|(_, piece)| *piece).collect();
+    let typed_move = match notation::parse_move(&input.text, &pieces, &turn) {
+        Ok(typed_move) => typed_move,
+        Err(err) => {
+            input.error = Some(format!("{}: {err}", input.text));
+            return;
+        }
+    };
+
+    let piece = pieces_query.iter().find_map(|(entity, piece)| {
+        (piece.x == typed_move.from.0 && piece.y == typed_move.from.1).then_some(entity)
+    });
+    let square = squares_query.iter().find_map(|(entity, square)| {
+        (square.x == typed_move.to.0 && square.y == typed_move.to.1).then_some(entity)
+    });
+    if let (Some(piece), Some(square)) = (piece, square) {
+        attempt_moves.send(AttemptMove {
+            piece,
+            square,
+            promotion: typed_move.promotion,
+        });
+    }
+    input.text.clear();
+    input.error = None;
+}
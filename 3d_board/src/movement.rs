@@ -1,5 +1,8 @@
-use bevy::app::AppExit;
 use bevy::prelude::*;
+use fluffy_chess_protocol::fluffy_chess_capnp::{piece, Color};
+use fluffy_chess_protocol::rules::{Board, Ending};
+
+use crate::undo::{PlayedMove, UndoStack};
 
 pub struct GameTimer {
     pub reset: bool,
@@ -7,30 +10,181 @@ pub struct GameTimer {
     pub black_time_left: Timer,
 }
 
+/// Whether each side can still castle, on the king side and on the queen side.
+#[derive(Clone, Copy, PartialEq)]
+pub struct CastlingRights {
+    pub white_king_side: bool,
+    pub white_queen_side: bool,
+    pub black_king_side: bool,
+    pub black_queen_side: bool,
+}
+impl Default for CastlingRights {
+    fn default() -> Self {
+        Self {
+            white_king_side: true,
+            white_queen_side: true,
+            black_king_side: true,
+            black_queen_side: true,
+        }
+    }
+}
+
+impl CastlingRights {
+    pub fn can_castle(&self, color: PieceColor, king_side: bool) -> bool {
+        match (color, king_side) {
+            (PieceColor::White, true) => self.white_king_side,
+            (PieceColor::White, false) => self.white_queen_side,
+            (PieceColor::Black, true) => self.black_king_side,
+            (PieceColor::Black, false) => self.black_queen_side,
+        }
+    }
+
+    /// Revoke the rights lost when a piece leaves or is taken on a square.
+    fn touch(&mut self, position: (u8, u8)) {
+        match position {
+            (0, 4) => {
+                self.white_king_side = false;
+                self.white_queen_side = false;
+            }
+            (0, 7) => self.white_king_side = false,
+            (0, 0) => self.white_queen_side = false,
+            (7, 4) => {
+                self.black_king_side = false;
+                self.black_queen_side = false;
+            }
+            (7, 7) => self.black_king_side = false,
+            (7, 0) => self.black_queen_side = false,
+            _ => {}
+        }
+    }
+}
+
 #[derive(Resource)]
 pub struct PlayerTurn {
     pub color: PieceColor,
+    pub castling: CastlingRights,
+    /// Square skipped by a pawn moving two squares on the last move, where it can be taken en passant
+    pub en_passant: Option<Square>,
+    /// How the game ended, when the side to move has no legal move
+    pub ending: Option<Ending>,
     timer: Option<GameTimer>,
 }
 impl Default for PlayerTurn {
     fn default() -> Self {
         Self {
             color: PieceColor::White,
+            castling: CastlingRights::default(),
+            en_passant: None,
+            ending: None,
             timer: None,
         }
     }
 }
 
+impl PlayerTurn {
+    /// Time left on the clock of the side to move, `None` when the game is not timed.
+    pub fn time_left(&self) -> Option<std::time::Duration> {
+        self.timer.as_ref().map(|timer| match self.color {
+            PieceColor::White => timer.white_time_left.remaining(),
+            PieceColor::Black => timer.black_time_left.remaining(),
+        })
+    }
+
+    /// Position of the pieces, with the castling and en passant the earlier moves allow.
+    pub fn board(&self, pieces: &[Piece]) -> Board {
+        let mut board = board(pieces);
+        for color in [PieceColor::White, PieceColor::Black] {
+            for king_side in [true, false] {
+                if self.castling.can_castle(color, king_side) {
+                    board.allow_castling(color.into(), king_side);
+                }
+            }
+        }
+        if let Some(square) = self.en_passant {
+            board.allow_en_passant((square.x, square.y));
+        }
+        board
+    }
+}
+
+/// Position of the pieces, where no side can castle nor take en passant.
+pub fn board(pieces: &[Piece]) -> Board {
+    Board::with_pieces(pieces.iter().map(|piece| {
+        (
+            (piece.x, piece.y),
+            (piece.color.into(), piece.piece_type.into()),
+        )
+    }))
+}
+
 #[derive(Event)]
 pub struct AttemptMove {
     pub piece: Entity,
     pub square: Entity,
+    /// Piece a pawn reaching the last rank becomes, a queen when `None`
+    pub promotion: Option<PieceType>,
 }
 
-#[derive(Event, Clone, Copy)]
+#[derive(Event, Clone, Copy, PartialEq, Debug)]
 pub struct Move {
     pub piece: Piece,
     pub square: Square,
+    /// Piece the pawn became on the last rank
+    pub promotion: Option<PieceType>,
+}
+
+impl Move {
+    /// The squares the rook moves between if this move is castling.
+    pub fn castling_rook(&self) -> Option<((u8, u8), (u8, u8))> {
+        if self.piece.piece_type != PieceType::King
+            || (self.piece.y as i8 - self.square.y as i8).abs() != 2
+        {
+            return None;
+        }
+        Some(if self.square.y > self.piece.y {
+            ((self.piece.x, 7), (self.piece.x, 5))
+        } else {
+            ((self.piece.x, 0), (self.piece.x, 3))
+        })
+    }
+
+    /// The square of the piece taken by this move, given the position it is played from.
+    pub fn captured_square(&self, pieces: &[Piece]) -> Option<(u8, u8)> {
+        if color_of_square((self.square.x, self.square.y), pieces).is_some() {
+            Some((self.square.x, self.square.y))
+        } else if self.piece.piece_type == PieceType::Pawn && self.piece.y != self.square.y {
+            // A pawn moving diagonally to an empty square takes en passant
+            Some((self.piece.x, self.square.y))
+        } else {
+            None
+        }
+    }
+
+    /// Play this move on a list of pieces, removing the piece it takes.
+    pub fn apply(&self, pieces: &mut Vec<Piece>) {
+        if let Some(captured) = self.captured_square(pieces) {
+            pieces.retain(|piece| piece.x != captured.0 || piece.y != captured.1);
+        }
+        if let Some((rook_from, rook_to)) = self.castling_rook() {
+            if let Some(rook) = pieces
+                .iter_mut()
+                .find(|piece| piece.x == rook_from.0 && piece.y == rook_from.1)
+            {
+                rook.x = rook_to.0;
+                rook.y = rook_to.1;
+            }
+        }
+        if let Some(piece) = pieces
+            .iter_mut()
+            .find(|piece| piece.x == self.piece.x && piece.y == self.piece.y)
+        {
+            piece.x = self.square.x;
+            piece.y = self.square.y;
+            if let Some(promotion) = self.promotion {
+                piece.piece_type = promotion;
+            }
+        }
+    }
 }
 
 pub struct MovementPlugin;
@@ -40,7 +194,7 @@ impl Plugin for MovementPlugin {
         app.add_event::<AttemptMove>()
             .add_event::<Move>()
             .init_resource::<PlayerTurn>()
-            .add_systems(Update, (move_to_square, crate::move_camera));
+            .add_systems(Update, move_to_square);
     }
 }
 
@@ -48,64 +202,110 @@ impl Plugin for MovementPlugin {
 pub fn move_to_square(
     mut commands: Commands,
     mut turn: ResMut<PlayerTurn>,
+    mut undo_stack: ResMut<UndoStack>,
     mut attempted_moves: EventReader<AttemptMove>,
     mut moves: EventWriter<Move>,
-    mut exit: EventWriter<AppExit>,
-    mut pieces_query: Query<(Entity, &mut Piece)>,
+    mut pieces_query: Query<(Entity, &mut Piece), Without<Captured>>,
+    captured_query: Query<&Piece, With<Captured>>,
     squares_query: Query<&Square>,
 ) {
+    // Moves from the server are played even while looking at an earlier position, the systems
+    // reading local input check that the board is live
     for attempted_move in attempted_moves.read() {
         let Ok(square) = squares_query.get(attempted_move.square) else {
             continue;
         };
-        let pieces_vec = pieces_query.iter().map(|(_, piece)| *piece).collect();
+        let pieces_vec: Vec<Piece> = pieces_query.iter().map(|(_, piece)| *piece).collect();
         let pieces_entity_vec: Vec<(Entity, Piece)> = pieces_query
             .iter()
             .map(|(entity, piece)| (entity, *piece))
             .collect();
-        let Ok((_, mut piece)) = pieces_query.get_mut(attempted_move.piece) else {
+        let Ok((_, piece)) = pieces_query.get(attempted_move.piece) else {
             continue;
         };
-        if !(piece.is_move_valid((square.x, square.y), pieces_vec)) {
+        let target = (square.x, square.y);
+        if !piece.is_legal(target, &pieces_vec, &turn) {
             continue;
         }
-        // Check if a piece of the opposite color exists in this square and despawn it
-        if let Some((other_entity, other_piece)) =
+        let promotion = if piece.piece_type == PieceType::Pawn && (target.0 == 0 || target.0 == 7) {
+            match attempted_move.promotion.unwrap_or(PieceType::Queen) {
+                PieceType::King | PieceType::Pawn => continue,
+                promotion => Some(promotion),
+            }
+        } else {
+            None
+        };
+        let done_move = Move {
+            piece: *piece,
+            square: *square,
+            promotion,
+        };
+        // Check if a piece of the opposite color is taken and set it aside
+        let captured = done_move.captured_square(&pieces_vec).and_then(|captured| {
             pieces_entity_vec
                 .iter()
                 .find(|(_other_entity, other_piece)| {
-                    other_piece.x == square.x
-                        && other_piece.y == square.y
+                    other_piece.x == captured.0
+                        && other_piece.y == captured.1
                         && other_piece.color != piece.color
                 })
-        {
-            // If the king is taken, we should exit
-            if other_piece.piece_type == PieceType::King {
-                println!(
-                    "{} won! Thanks for playing!",
-                    match turn.color {
-                        PieceColor::White => "White",
-                        PieceColor::Black => "Black",
-                    }
-                );
-                exit.send(AppExit);
-            }
-            commands.entity(*other_entity).despawn_recursive()
+                .copied()
+        });
+        if let Some((other_entity, other_piece)) = captured {
+            // Line taken pieces up in their tray in the order they were taken
+            let slot = captured_query
+                .iter()
+                .filter(|captured| captured.color == other_piece.color)
+                .count() as u8;
+            commands.entity(other_entity).insert(Captured { slot });
         }
 
-        // Move piece
-        let done_move = Move {
-            piece: *piece,
-            square: *square,
-        };
+        undo_stack.push(PlayedMove {
+            piece: attempted_move.piece,
+            done_move,
+            captured,
+            castling: turn.castling,
+            en_passant: turn.en_passant,
+        });
 
-        piece.x = square.x;
-        piece.y = square.y;
+        // Move piece, and the rook along with the king when castling
+        let mut moved = vec![(attempted_move.piece, target)];
+        if let Some((rook_from, rook_to)) = done_move.castling_rook() {
+            if let Some((rook_entity, _)) = pieces_entity_vec
+                .iter()
+                .find(|(_, rook)| rook.x == rook_from.0 && rook.y == rook_from.1)
+            {
+                moved.push((*rook_entity, rook_to));
+            }
+        }
+        for (entity, (x, y)) in moved {
+            if let Ok((_, mut piece)) = pieces_query.get_mut(entity) {
+                piece.x = x;
+                piece.y = y;
+            }
+        }
+        if let Some(promotion) = promotion {
+            if let Ok((_, mut piece)) = pieces_query.get_mut(attempted_move.piece) {
+                piece.piece_type = promotion;
+            }
+        }
 
-        turn.color = match turn.color {
-            PieceColor::White => PieceColor::Black,
-            PieceColor::Black => PieceColor::White,
+        turn.castling.touch((done_move.piece.x, done_move.piece.y));
+        turn.castling.touch(target);
+        turn.en_passant = if done_move.piece.piece_type == PieceType::Pawn
+            && (done_move.piece.x as i8 - square.x as i8).abs() == 2
+        {
+            Some(Square {
+                x: (done_move.piece.x + square.x) / 2,
+                y: square.y,
+            })
+        } else {
+            None
         };
+        turn.color = turn.color.opposite();
+        let mut after = pieces_vec;
+        done_move.apply(&mut after);
+        turn.ending = turn.board(&after).ending(turn.color.into());
 
         // We need the information on the origin position of the piece
         assert!(done_move.square.x != done_move.piece.x || done_move.square.y != done_move.piece.y);
@@ -114,13 +314,22 @@ pub fn move_to_square(
     }
 }
 
-#[derive(Component, Clone, Copy, PartialEq)]
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
 pub enum PieceColor {
     White,
     Black,
 }
 
-#[derive(Component, Clone, Copy, PartialEq)]
+impl PieceColor {
+    pub fn opposite(self) -> Self {
+        match self {
+            PieceColor::White => PieceColor::Black,
+            PieceColor::Black => PieceColor::White,
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
 pub enum PieceType {
     King,
     Queen,
@@ -130,7 +339,27 @@ pub enum PieceType {
     Pawn,
 }
 
-#[derive(Clone, Copy, Component)]
+impl PieceType {
+    /// Usual material value of the piece, in pawns.
+    pub fn value(self) -> u32 {
+        match self {
+            PieceType::King => 0,
+            PieceType::Queen => 9,
+            PieceType::Bishop | PieceType::Knight => 3,
+            PieceType::Rook => 5,
+            PieceType::Pawn => 1,
+        }
+    }
+}
+
+/// A piece taken off the board, kept aside so the move can be taken back.
+#[derive(Component, Clone, Copy)]
+pub struct Captured {
+    /// Place in the tray of taken pieces of its color
+    pub slot: u8,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Component)]
 pub struct Piece {
     pub color: PieceColor,
     pub piece_type: PieceType,
@@ -140,7 +369,7 @@ pub struct Piece {
 }
 
 /// Returns None if square is empty, returns a Some with the color if not
-fn color_of_square(pos: (u8, u8), pieces: &Vec<Piece>) -> Option<PieceColor> {
+fn color_of_square(pos: (u8, u8), pieces: &[Piece]) -> Option<PieceColor> {
     for piece in pieces {
         if piece.x == pos.0 && piece.y == pos.1 {
             return Some(piece.color);
@@ -149,150 +378,60 @@ fn color_of_square(pos: (u8, u8), pieces: &Vec<Piece>) -> Option<PieceColor> {
     None
 }
 
-fn is_path_empty(begin: (u8, u8), end: (u8, u8), pieces: &Vec<Piece>) -> bool {
-    // Same column
-    if begin.0 == end.0 {
-        for piece in pieces {
-            if piece.x == begin.0
-                && ((piece.y > begin.1 && piece.y < end.1)
-                    || (piece.y > end.1 && piece.y < begin.1))
-            {
-                return false;
-            }
-        }
-    }
-    // Same row
-    if begin.1 == end.1 {
-        for piece in pieces {
-            if piece.y == begin.1
-                && ((piece.x > begin.0 && piece.x < end.0)
-                    || (piece.x > end.0 && piece.x < begin.0))
-            {
-                return false;
-            }
-        }
+impl Piece {
+    /// Whether this piece can move to `new_position` without leaving its king in check, castling
+    /// and en passant included
+    pub fn is_legal(&self, new_position: (u8, u8), pieces: &[Piece], turn: &PlayerTurn) -> bool {
+        turn.board(pieces).is_legal((self.x, self.y), new_position)
     }
+}
 
-    // Diagonals
-    let x_diff = (begin.0 as i8 - end.0 as i8).abs();
-    let y_diff = (begin.1 as i8 - end.1 as i8).abs();
-    if x_diff == y_diff {
-        for i in 1..x_diff {
-            let pos = if begin.0 < end.0 && begin.1 < end.1 {
-                // left bottom - right top
-                (begin.0 + i as u8, begin.1 + i as u8)
-            } else if begin.0 < end.0 && begin.1 > end.1 {
-                // left top - right bottom
-                (begin.0 + i as u8, begin.1 - i as u8)
-            } else if begin.0 > end.0 && begin.1 < end.1 {
-                // right bottom - left top
-                (begin.0 - i as u8, begin.1 + i as u8)
-            } else {
-                // begin.0 > end.0 && begin.1 > end.1
-                // right top - left bottom
-                (begin.0 - i as u8, begin.1 - i as u8)
-            };
-
-            if color_of_square(pos, pieces).is_some() {
-                return false;
-            }
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
+pub struct Square {
+    pub x: u8,
+    pub y: u8,
+}
+
+impl From<Color> for PieceColor {
+    fn from(value: Color) -> Self {
+        match value {
+            Color::White => PieceColor::White,
+            Color::Black => PieceColor::Black,
         }
     }
-
-    true
 }
 
-impl Piece {
-    /// Returns the possible_positions that are available
-    pub fn is_move_valid(&self, new_position: (u8, u8), pieces: Vec<Piece>) -> bool {
-        // If there's a piece of the same color in the same square, it can't move
-        let square_color = color_of_square(new_position, &pieces);
-        if square_color == Some(self.color) {
-            return false;
+impl From<PieceColor> for Color {
+    fn from(value: PieceColor) -> Self {
+        match value {
+            PieceColor::White => Color::White,
+            PieceColor::Black => Color::Black,
         }
+    }
+}
 
-        match self.piece_type {
-            PieceType::King => {
-                // Horizontal
-                ((self.x as i8 - new_position.0 as i8).abs() == 1
-                    && (self.y == new_position.1))
-                    // Vertical
-                    || ((self.y as i8 - new_position.1 as i8).abs() == 1
-                    && (self.x == new_position.0))
-                    // Diagonal
-                    || ((self.x as i8 - new_position.0 as i8).abs() == 1
-                    && (self.y as i8 - new_position.1 as i8).abs() == 1)
-            }
-            PieceType::Queen => {
-                is_path_empty((self.x, self.y), new_position, &pieces)
-                    && ((self.x as i8 - new_position.0 as i8).abs()
-                        == (self.y as i8 - new_position.1 as i8).abs()
-                        || ((self.x == new_position.0 && self.y != new_position.1)
-                            || (self.y == new_position.1 && self.x != new_position.0)))
-            }
-            PieceType::Bishop => {
-                is_path_empty((self.x, self.y), new_position, &pieces)
-                    && (self.x as i8 - new_position.0 as i8).abs()
-                        == (self.y as i8 - new_position.1 as i8).abs()
-            }
-            PieceType::Knight => {
-                ((self.x as i8 - new_position.0 as i8).abs() == 2
-                    && (self.y as i8 - new_position.1 as i8).abs() == 1)
-                    || ((self.x as i8 - new_position.0 as i8).abs() == 1
-                        && (self.y as i8 - new_position.1 as i8).abs() == 2)
-            }
-            PieceType::Rook => {
-                is_path_empty((self.x, self.y), new_position, &pieces)
-                    && ((self.x == new_position.0 && self.y != new_position.1)
-                        || (self.y == new_position.1 && self.x != new_position.0))
-            }
-            PieceType::Pawn => {
-                if self.color == PieceColor::White {
-                    // Normal move
-                    new_position.0 as i8 - self.x as i8 == 1
-                        && (self.y == new_position.1)
-                        && square_color.is_none()
-                    ||
-
-                    // Move 2 squares
-                     self.x == 1
-                        && new_position.0 as i8 - self.x as i8 == 2
-                        && (self.y == new_position.1)
-                        && is_path_empty((self.x, self.y), new_position, &pieces)
-                        && square_color.is_none()
-                    ||
-
-                    // Take piece
-                     new_position.0 as i8 - self.x as i8 == 1
-                        && (self.y as i8 - new_position.1 as i8).abs() == 1
-                        && square_color == Some(PieceColor::Black)
-                } else {
-                    // Normal move
-                    new_position.0 as i8 - self.x as i8 == -1
-                        && (self.y == new_position.1)
-                        && square_color.is_none()
-                    ||
-
-                    // Move 2 squares
-                    self.x == 6
-                        && new_position.0 as i8 - self.x as i8 == -2
-                        && (self.y == new_position.1)
-                        && is_path_empty((self.x, self.y), new_position, &pieces)
-                        && square_color.is_none()
-                    ||
-
-                    // Take piece
-                    new_position.0 as i8 - self.x as i8 == -1
-                        && (self.y as i8 - new_position.1 as i8).abs() == 1
-                        && square_color == Some(PieceColor::White)
-                }
-            }
+impl From<piece::Type> for PieceType {
+    fn from(value: piece::Type) -> Self {
+        match value {
+            piece::Type::King => PieceType::King,
+            piece::Type::Queen => PieceType::Queen,
+            piece::Type::Bishop => PieceType::Bishop,
+            piece::Type::Knight => PieceType::Knight,
+            piece::Type::Rook => PieceType::Rook,
+            piece::Type::Pawn => PieceType::Pawn,
         }
     }
 }
 
-#[derive(Component, Clone, Copy)]
-pub struct Square {
-    pub x: u8,
-    pub y: u8,
+impl From<PieceType> for piece::Type {
+    fn from(value: PieceType) -> Self {
+        match value {
+            PieceType::King => piece::Type::King,
+            PieceType::Queen => piece::Type::Queen,
+            PieceType::Bishop => piece::Type::Bishop,
+            PieceType::Knight => piece::Type::Knight,
+            PieceType::Rook => piece::Type::Rook,
+            PieceType::Pawn => piece::Type::Pawn,
+        }
+    }
 }
@@ -0,0 +1,1355 @@
+use crate::chat::{ChatAuthor, ChatLine, ChatReceived, SendChat};
+use crate::history::{record_moves, MoveHistory};
+use crate::movement::{
+    move_to_square, AttemptMove, Captured, Move, Piece, PieceColor, PieceType, PlayerTurn, Square,
+};
+use crate::transport;
+use crate::undo::{undo_moves, UndoCommand, UndoRequest};
+use bevy::prelude::*;
+use capnp::capability::Promise;
+use capnp_rpc::pry;
+use fluffy_chess_protocol::fluffy_chess_capnp::{
+    game_config, game_maker, game_result, game_side, move_, player, social, social_listener,
+    spectator, square, PROTOCOL_VERSION,
+};
+use fluffy_chess_protocol::{FromReader, ToBuilder};
+use futures::channel::{mpsc, oneshot};
+use futures::StreamExt;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Where the game server is, and how to reach it.
+#[derive(Clone)]
+pub struct ServerConfig {
+    /// Host and port
+    pub address: String,
+    /// Encrypt the connection with TLS
+    pub tls: bool,
+    /// PEM file of a certificate to trust besides the usual authorities, for a server with a
+    /// self-signed one
+    pub trusted_certificate: Option<PathBuf>,
+}
+
+/// Play against someone else through the game server at `server`, or locally when it is `None`.
+pub struct NetworkPlugin {
+    pub server: Option<ServerConfig>,
+    pub user: String,
+    /// Watch the game with this ID instead of playing
+    pub watch: Option<u64>,
+    /// Whether the game changes the ratings of the players
+    pub rated: bool,
+    /// Invite this user to the game instead of looking for an opponent
+    pub challenge: Option<String>,
+}
+
+impl Plugin for NetworkPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TakebackAnswer>()
+            .add_event::<GameAction>()
+            .add_event::<ChallengeAnswer>();
+        let Some(server) = self.server.clone() else {
+            return;
+        };
+        let user = self.user.clone();
+        let (watch, rated) = (self.watch, self.rated);
+        let challenge = self.challenge.clone();
+        let (commands, commands_receiver) = mpsc::unbounded();
+        let (events_sender, events) = std::sync::mpsc::channel();
+        transport::spawn_client(move || {
+            run_client(
+                server,
+                user,
+                watch,
+                rated,
+                challenge,
+                commands_receiver,
+                events_sender,
+            )
+        });
+
+        app.insert_resource(OnlineGame {
+            color: None,
+            watching: watch,
+            commands,
+            events: Mutex::new(events),
+            takeback_asked: false,
+            takeback_requested: None,
+            draw_asked: false,
+            draw_offered: false,
+            result: None,
+            challenge: None,
+            replay: VecDeque::new(),
+            resyncing: false,
+        })
+        .add_systems(
+            Update,
+            (
+                // Moves are replayed one per frame, each after the previous one is on the board
+                receive_network_events
+                    .after(move_to_square)
+                    .after(undo_moves),
+                // Moves are numbered from the history
+                send_moves.after(record_moves),
+                send_chat,
+                request_takebacks,
+                answer_takebacks,
+                send_game_actions,
+                answer_challenges,
+            ),
+        );
+    }
+}
+
+/// Sent from the game to the connection with the server.
+enum NetworkCommand {
+    Move(WireMove),
+    RequestTakeback,
+    Chat(String),
+    Action(GameAction),
+    /// Ask for the moves of the game again, the board does not fit the last one
+    Resync,
+}
+
+/// Sent from the connection with the server to the game.
+enum NetworkEvent {
+    Joined(PieceColor),
+    Move(WireMove),
+    /// Every move played in the game, to rebuild the board from
+    Resynced(Vec<WireMove>),
+    MoveRejected(String),
+    TakebackRequested(oneshot::Sender<bool>),
+    TakebackAnswered(bool),
+    /// The server refused the takeback asked by the local player
+    TakebackRejected(String),
+    /// The last move of the watched game was taken back
+    TakenBack,
+    Chat {
+        from: String,
+        message: String,
+    },
+    /// The server refused a message of the local player
+    ChatRejected(String),
+    DrawOffered,
+    DrawDeclined,
+    GameEnded(GameResult),
+    ActionRejected(GameAction, String),
+    /// News about the friends of the local player
+    Social(String),
+    /// Someone invites the local player to a game, described by the text
+    Challenged(String, oneshot::Sender<bool>),
+    Disconnected(String),
+}
+
+/// A game played against someone else through the server.
+#[derive(Resource)]
+pub struct OnlineGame {
+    /// Color of the local player, once the server has found a game. Always `None` when watching.
+    pub color: Option<PieceColor>,
+    /// ID of the game being watched, read-only
+    pub watching: Option<u64>,
+    commands: mpsc::UnboundedSender<NetworkCommand>,
+    events: Mutex<std::sync::mpsc::Receiver<NetworkEvent>>,
+    /// Whether the local player is waiting for the opponent to answer a takeback request
+    takeback_asked: bool,
+    /// Answer to the takeback asked by the opponent
+    takeback_requested: Option<oneshot::Sender<bool>>,
+    /// Whether the local player is waiting for the opponent to answer a draw offer
+    draw_asked: bool,
+    /// Whether the opponent offers a draw
+    draw_offered: bool,
+    /// How the game ended, once the server says it is over
+    pub result: Option<GameResult>,
+    /// Description of the game someone invites the local player to, and the answer to it
+    challenge: Option<(String, oneshot::Sender<bool>)>,
+    /// Moves of the server played again from the start, after the board got out of step with it
+    replay: VecDeque<WireMove>,
+    /// Whether the moves of the game were asked for again, the moves notified meanwhile wait in
+    /// `replay`
+    resyncing: bool,
+}
+
+impl OnlineGame {
+    /// Whether the opponent is waiting for an answer to their takeback request.
+    pub fn takeback_requested(&self) -> bool {
+        self.takeback_requested.is_some()
+    }
+
+    pub fn draw_asked(&self) -> bool {
+        self.draw_asked
+    }
+
+    pub fn draw_offered(&self) -> bool {
+        self.draw_offered
+    }
+
+    /// Description of the game someone invites the local player to, while they wait for an
+    /// answer.
+    pub fn challenge(&self) -> Option<&str> {
+        self.challenge.as_ref().map(|(text, _)| text.as_str())
+    }
+}
+
+/// Whether the pieces of the side to move can be played from this client.
+pub fn can_play(turn: &PlayerTurn, online: Option<&OnlineGame>) -> bool {
+    turn.ending.is_none()
+        && online.map_or(true, |online| {
+            online.result.is_none() && online.color == Some(turn.color)
+        })
+}
+
+/// Answer of the local player to the takeback requested by the opponent.
+#[derive(Event, Clone, Copy)]
+pub struct TakebackAnswer {
+    pub accept: bool,
+}
+
+/// Answer of the local player to the game someone invites them to.
+#[derive(Event, Clone, Copy)]
+pub struct ChallengeAnswer {
+    pub accept: bool,
+}
+
+/// What the local player can do to end an online game, besides moving.
+#[derive(Event, Clone, Copy, PartialEq)]
+pub enum GameAction {
+    OfferDraw,
+    AcceptDraw,
+    DeclineDraw,
+    Resign,
+    /// End the game without a result, before both players have moved
+    Abort,
+}
+
+/// How an online game ended.
+#[derive(Clone, Copy, PartialEq)]
+pub enum GameResult {
+    Checkmate { winner: PieceColor },
+    Stalemate,
+    Resignation { winner: PieceColor },
+    Abandoned { winner: PieceColor },
+    DrawAgreed,
+    Aborted,
+}
+
+/// Play a move of the server on the board. Returns `false` when it doesn't fit the position.
+fn play_wire_move(
+    wire_move: WireMove,
+    turn: &PlayerTurn,
+    attempt_moves: &mut EventWriter<AttemptMove>,
+    pieces_query: &Query<(Entity, &Piece), Without<Captured>>,
+    squares_query: &Query<(Entity, &Square)>,
+) -> bool {
+    let pieces: Vec<Piece> = pieces_query.iter().map(|(_, piece)| *piece).collect();
+    let Some(done_move) = wire_move.to_move(&pieces) else {
+        return false;
+    };
+    let target = (done_move.square.x, done_move.square.y);
+    if done_move.piece.color != turn.color || !done_move.piece.is_legal(target, &pieces, turn) {
+        return false;
+    }
+    let piece = pieces_query.iter().find_map(|(entity, piece)| {
+        (piece.x == done_move.piece.x && piece.y == done_move.piece.y).then_some(entity)
+    });
+    let square = squares_query.iter().find_map(|(entity, square)| {
+        (square.x == done_move.square.x && square.y == done_move.square.y).then_some(entity)
+    });
+    let (Some(piece), Some(square)) = (piece, square) else {
+        return false;
+    };
+    attempt_moves.send(AttemptMove {
+        piece,
+        square,
+        promotion: done_move.promotion,
+    });
+    true
+}
+
+#[allow(clippy::too_many_arguments)]
+fn receive_network_events(
+    mut online: ResMut<OnlineGame>,
+    turn: Res<PlayerTurn>,
+    mut attempt_moves: EventWriter<AttemptMove>,
+    mut undo_commands: EventWriter<UndoCommand>,
+    mut chat: EventWriter<ChatReceived>,
+    history: Res<MoveHistory>,
+    pieces_query: Query<(Entity, &Piece), Without<Captured>>,
+    squares_query: Query<(Entity, &Square)>,
+) {
+    let online = &mut *online;
+    if !online.resyncing {
+        if let Some(wire_move) = online.replay.pop_front() {
+            if !play_wire_move(
+                wire_move,
+                &turn,
+                &mut attempt_moves,
+                &pieces_query,
+                &squares_query,
+            ) {
+                online.replay.clear();
+                error!(
+                    "Move {} of the server does not fit the board",
+                    wire_move.number
+                );
+                chat.send(ChatReceived(ChatLine {
+                    author: ChatAuthor::Server,
+                    text: format!(
+                        "Move {} of the server does not fit the board either, the game can't be \
+                        followed",
+                        wire_move.number
+                    ),
+                }));
+            }
+            // The next move is played once this one is on the board
+            return;
+        }
+    }
+    let events = online.events.get_mut().unwrap();
+    while let Ok(event) = events.try_recv() {
+        match event {
+            NetworkEvent::Joined(color) => {
+                // An accepted challenge replaces the game that had not started yet
+                online.color = Some(color);
+                online.takeback_asked = false;
+                online.takeback_requested = None;
+                online.draw_asked = false;
+                online.draw_offered = false;
+                online.result = None;
+            }
+            NetworkEvent::Move(wire_move) => {
+                // The opponent moved instead of answering the draw offer
+                online.draw_asked = false;
+                if online.resyncing {
+                    online.replay.push_back(wire_move);
+                    continue;
+                }
+                if !play_wire_move(
+                    wire_move,
+                    &turn,
+                    &mut attempt_moves,
+                    &pieces_query,
+                    &squares_query,
+                ) {
+                    warn!(
+                        "Move {} of the server does not fit the board",
+                        wire_move.number
+                    );
+                    chat.send(ChatReceived(ChatLine {
+                        author: ChatAuthor::Server,
+                        text: format!(
+                            "Move {} of the server does not fit the board, asking for the moves \
+                            of the game again",
+                            wire_move.number
+                        ),
+                    }));
+                    online.resyncing = true;
+                    let _ = online.commands.unbounded_send(NetworkCommand::Resync);
+                }
+                // The next move is looked up once this one is played
+                break;
+            }
+            NetworkEvent::Resynced(moves) => {
+                online.resyncing = false;
+                // The moves notified while waiting for the list may be in it already
+                let waiting = std::mem::take(&mut online.replay);
+                let ply = moves.len() as u32;
+                online.replay = moves.into();
+                online.replay.extend(
+                    waiting
+                        .into_iter()
+                        .filter(|wire_move| wire_move.number >= ply),
+                );
+                // Take every move back, the board is rebuilt from the start on the next frames
+                for _ in history.records() {
+                    undo_commands.send(UndoCommand::Undo);
+                }
+                break;
+            }
+            NetworkEvent::MoveRejected(reason) => {
+                // The server did not accept the move, take it back from the board
+                warn!("Move rejected by the server: {reason}");
+                chat.send(ChatReceived(ChatLine {
+                    author: ChatAuthor::Server,
+                    text: format!("Move taken back, the server refused it: {reason}"),
+                }));
+                undo_commands.send(UndoCommand::Undo);
+            }
+            NetworkEvent::TakebackRequested(answer) => online.takeback_requested = Some(answer),
+            NetworkEvent::TakebackAnswered(accepted) => {
+                online.takeback_asked = false;
+                if accepted {
+                    undo_commands.send(UndoCommand::Undo);
+                }
+            }
+            NetworkEvent::TakebackRejected(reason) => {
+                online.takeback_asked = false;
+                warn!("Takeback refused by the server: {reason}");
+                chat.send(ChatReceived(ChatLine {
+                    author: ChatAuthor::Server,
+                    text: format!("Takeback refused: {reason}"),
+                }));
+            }
+            NetworkEvent::TakenBack => undo_commands.send(UndoCommand::Undo),
+            NetworkEvent::Chat { from, message } => chat.send(ChatReceived(ChatLine {
+                author: ChatAuthor::Opponent(from),
+                text: message,
+            })),
+            NetworkEvent::ChatRejected(reason) => chat.send(ChatReceived(ChatLine {
+                author: ChatAuthor::Server,
+                text: format!("Not sent: {reason}"),
+            })),
+            NetworkEvent::DrawOffered => online.draw_offered = true,
+            NetworkEvent::DrawDeclined => online.draw_asked = false,
+            NetworkEvent::GameEnded(result) => {
+                online.result = Some(result);
+                online.draw_asked = false;
+                online.draw_offered = false;
+            }
+            NetworkEvent::Social(news) => chat.send(ChatReceived(ChatLine {
+                author: ChatAuthor::Server,
+                text: news,
+            })),
+            NetworkEvent::Challenged(text, answer) => {
+                // Only a game without moves can be left for another one, dropping the answer
+                // declines
+                if online.challenge.is_some() || !history.records().is_empty() {
+                    chat.send(ChatReceived(ChatLine {
+                        author: ChatAuthor::Server,
+                        text: format!("Declined while playing: {text}"),
+                    }));
+                } else {
+                    online.challenge = Some((text, answer));
+                }
+            }
+            NetworkEvent::ActionRejected(action, reason) => {
+                warn!("The server refused the action: {reason}");
+                chat.send(ChatReceived(ChatLine {
+                    author: ChatAuthor::Server,
+                    text: format!("Refused: {reason}"),
+                }));
+                if action == GameAction::OfferDraw {
+                    online.draw_asked = false;
+                }
+            }
+            NetworkEvent::Disconnected(reason) => {
+                error!("Disconnected from the server: {reason}");
+                chat.send(ChatReceived(ChatLine {
+                    author: ChatAuthor::Server,
+                    text: format!("Disconnected from the server: {reason}"),
+                }));
+            }
+        }
+    }
+}
+
+/// Send the moves of the local player to the server.
+fn send_moves(
+    mut online: ResMut<OnlineGame>,
+    history: Res<MoveHistory>,
+    mut moves: EventReader<Move>,
+) {
+    // The moves of this frame are already recorded, the last ones of the history
+    let first = history.records().len().saturating_sub(moves.len());
+    for (i, done_move) in moves.read().enumerate() {
+        if online.color == Some(done_move.piece.color) {
+            // Moving declines the draw offered by the opponent
+            online.draw_offered = false;
+            let wire_move = WireMove::new(done_move, (first + i) as u32);
+            let _ = online
+                .commands
+                .unbounded_send(NetworkCommand::Move(wire_move));
+        }
+    }
+}
+
+/// Send the chat messages of the local player to the opponent.
+fn send_chat(
+    online: Res<OnlineGame>,
+    mut messages: EventReader<SendChat>,
+    mut chat: EventWriter<ChatReceived>,
+) {
+    for SendChat(message) in messages.read() {
+        if online.watching.is_some() {
+            chat.send(ChatReceived(ChatLine {
+                author: ChatAuthor::Server,
+                text: "Spectators can't chat with the players".to_string(),
+            }));
+            continue;
+        }
+        let _ = online
+            .commands
+            .unbounded_send(NetworkCommand::Chat(message.clone()));
+    }
+}
+
+/// Ask the opponent before taking back a move, there is no redo in online games.
+fn request_takebacks(mut online: ResMut<OnlineGame>, mut requests: EventReader<UndoRequest>) {
+    for request in requests.read() {
+        // Spectators have no say in the game
+        if *request == UndoRequest::Undo && !online.takeback_asked && online.watching.is_none() {
+            online.takeback_asked = true;
+            let _ = online
+                .commands
+                .unbounded_send(NetworkCommand::RequestTakeback);
+        }
+    }
+}
+
+fn answer_takebacks(
+    mut online: ResMut<OnlineGame>,
+    mut answers: EventReader<TakebackAnswer>,
+    mut undo_commands: EventWriter<UndoCommand>,
+) {
+    for answer in answers.read() {
+        let Some(sender) = online.takeback_requested.take() else {
+            continue;
+        };
+        if sender.send(answer.accept).is_ok() && answer.accept {
+            undo_commands.send(UndoCommand::Undo);
+        }
+    }
+}
+
+fn answer_challenges(mut online: ResMut<OnlineGame>, mut answers: EventReader<ChallengeAnswer>) {
+    for answer in answers.read() {
+        if let Some((_, sender)) = online.challenge.take() {
+            let _ = sender.send(answer.accept);
+        }
+    }
+}
+
+/// Send the draw offers, resignations and aborts of the local player to the server.
+fn send_game_actions(mut online: ResMut<OnlineGame>, mut actions: EventReader<GameAction>) {
+    for action in actions.read() {
+        if online.watching.is_some() || online.result.is_some() {
+            continue;
+        }
+        match action {
+            GameAction::OfferDraw => online.draw_asked = true,
+            GameAction::AcceptDraw | GameAction::DeclineDraw => online.draw_offered = false,
+            GameAction::Resign | GameAction::Abort => {}
+        }
+        let _ = online
+            .commands
+            .unbounded_send(NetworkCommand::Action(*action));
+    }
+}
+
+/// Run the connection with the server, until the game closes.
+async fn run_client(
+    server: ServerConfig,
+    user: String,
+    watch: Option<u64>,
+    rated: bool,
+    challenge: Option<String>,
+    commands: mpsc::UnboundedReceiver<NetworkCommand>,
+    events: std::sync::mpsc::Sender<NetworkEvent>,
+) {
+    let result = match watch {
+        Some(id) => watch_online(server, id, commands, events.clone()).await,
+        None => play_online(server, user, rated, challenge, commands, events.clone()).await,
+    };
+    if let Err(err) = result {
+        let _ = events.send(NetworkEvent::Disconnected(err.to_string()));
+    }
+}
+
+/// Connect to the server. Fails if the server speaks another version of the protocol.
+async fn connect(server: &ServerConfig) -> Result<game_maker::Client, Box<dyn std::error::Error>> {
+    let handshake = transport::open(server).await?;
+    let mut request = handshake.hello_request();
+    request.get().set_version(PROTOCOL_VERSION);
+    Ok(request.send().promise.await?.get()?.get_game_maker()?)
+}
+
+/// A game being played on the server, as listed to spectators.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct LiveGame {
+    pub id: u64,
+    pub white: String,
+    pub black: String,
+    /// Number of moves played
+    pub ply: u32,
+}
+
+/// Ask the server for the games being played, blocking until it answers.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn list_games(server: &ServerConfig) -> Result<Vec<LiveGame>, Box<dyn std::error::Error>> {
+    transport::block_on_local(async {
+        let game_maker = connect(server).await?;
+        let directory = game_maker
+            .directory_request()
+            .send()
+            .promise
+            .await?
+            .get()?
+            .get_directory()?;
+        let response = directory.games_request().send().promise.await?;
+        let mut games = Vec::new();
+        for info in response.get()?.get_games()?.iter() {
+            games.push(LiveGame {
+                id: info.get_id(),
+                white: info.get_white()?.to_str()?.to_string(),
+                black: info.get_black()?.to_str()?.to_string(),
+                ply: info.get_ply(),
+            });
+        }
+        Ok(games)
+    })
+}
+
+/// A friend of the user, as listed by the server.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct Friend {
+    pub username: String,
+    pub online: bool,
+}
+
+/// Change to the friends of the user, asked on the command line.
+#[cfg(not(target_arch = "wasm32"))]
+pub enum FriendChange {
+    /// Ask to become friends, or accept their request
+    Add(String),
+    /// Stop being friends, or decline their request
+    Remove(String),
+}
+
+/// Open a session of `user`, who is shown online to their friends until it is dropped.
+/// The sides of the games accepted from challenges are sent to `accepted`.
+async fn open_social(
+    game_maker: &game_maker::Client,
+    user: &str,
+    events: std::sync::mpsc::Sender<NetworkEvent>,
+    accepted: mpsc::UnboundedSender<game_side::Client>,
+) -> capnp::Result<social::Client> {
+    let mut request = game_maker.social_request();
+    request.get().set_user(user.into());
+    request
+        .get()
+        .set_listener(capnp_rpc::new_client(SocialListenerImpl {
+            events,
+            accepted,
+        }));
+    request.send().promise.await?.get()?.get_social()
+}
+
+/// Change the friends of the user if asked, then list their friends and the users asking to
+/// become one, blocking until the server answers.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn friends(
+    server: &ServerConfig,
+    user: &str,
+    change: Option<FriendChange>,
+) -> Result<(Vec<Friend>, Vec<String>), Box<dyn std::error::Error>> {
+    transport::block_on_local(async {
+        let game_maker = connect(server).await?;
+        // Nothing listens to the news of the session while the command runs, challenges are
+        // declined
+        let social = open_social(
+            &game_maker,
+            user,
+            std::sync::mpsc::channel().0,
+            mpsc::unbounded().0,
+        )
+        .await?;
+        match change {
+            Some(FriendChange::Add(username)) => {
+                let mut request = social.send_friend_request_request();
+                request.get().set_username(username.as_str().into());
+                request.send().promise.await?;
+            }
+            Some(FriendChange::Remove(username)) => {
+                let mut request = social.remove_friend_request();
+                request.get().set_username(username.as_str().into());
+                request.send().promise.await?;
+            }
+            None => {}
+        }
+
+        let response = social.friends_request().send().promise.await?;
+        let mut friends = Vec::new();
+        for friend in response.get()?.get_friends()?.iter() {
+            friends.push(Friend {
+                username: friend.get_username()?.to_str()?.to_string(),
+                online: friend.get_online(),
+            });
+        }
+        let response = social.friend_requests_request().send().promise.await?;
+        let mut requests = Vec::new();
+        for username in response.get()?.get_usernames()?.iter() {
+            requests.push(username?.to_str()?.to_string());
+        }
+        Ok((friends, requests))
+    })
+}
+
+/// Describe the game wanted by `user`, against anyone unless `adversary` is given.
+fn write_config(
+    mut game_config: game_config::Builder,
+    user: &str,
+    rated: bool,
+    adversary: Option<&str>,
+) {
+    game_config.set_user(user.into());
+    match adversary {
+        Some(username) => game_config
+            .reborrow()
+            .init_adversary()
+            .set_user(username.into()),
+        None => game_config.reborrow().init_adversary().set_any(()),
+    }
+    game_config.set_rated(rated);
+}
+
+/// Tell the game which color the local player has in the game of `game_side`.
+async fn join(
+    game_side: &game_side::Client,
+    events: &std::sync::mpsc::Sender<NetworkEvent>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let color = game_side
+        .color_request()
+        .send()
+        .promise
+        .await?
+        .get()?
+        .get_color()?;
+    events.send(NetworkEvent::Joined(color.into()))?;
+    Ok(())
+}
+
+async fn play_online(
+    server: ServerConfig,
+    user: String,
+    rated: bool,
+    challenge: Option<String>,
+    mut commands: mpsc::UnboundedReceiver<NetworkCommand>,
+    events: std::sync::mpsc::Sender<NetworkEvent>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let game_maker = connect(&server).await?;
+    // Stay online for friends while playing, their news show in the chat
+    let (accepted_sender, mut accepted) = mpsc::unbounded();
+    let _social = open_social(&game_maker, &user, events.clone(), accepted_sender).await?;
+
+    let player = capnp_rpc::new_client(PlayerImpl {
+        events: events.clone(),
+    });
+    let mut game_side = match challenge {
+        Some(username) => {
+            events.send(NetworkEvent::Social(format!(
+                "Waiting for {username} to accept the challenge"
+            )))?;
+            let mut request = game_maker.challenge_request();
+            write_config(
+                request.get().init_game_config(),
+                &user,
+                rated,
+                Some(&username),
+            );
+            request.get().set_player(player);
+            match request.send().promise.await {
+                Ok(response) => response.get()?.get_game_side()?,
+                Err(err) => {
+                    events.send(NetworkEvent::Social(format!("The challenge failed: {err}")))?;
+                    return Ok(());
+                }
+            }
+        }
+        None => {
+            let mut request = game_maker.find_game_request();
+            write_config(request.get().init_game_config(), &user, rated, None);
+            request.get().set_player(player);
+            request.send().promise.await?.get()?.get_game_side()?
+        }
+    };
+    join(&game_side, &events).await?;
+
+    loop {
+        let command = futures::select! {
+            command = commands.next() => match command {
+                Some(command) => command,
+                None => break,
+            },
+            challenge_side = accepted.next() => {
+                let Some(challenge_side) = challenge_side else {
+                    continue;
+                };
+                // The game was left before any move, for the one of the challenge
+                let _ = game_side.abort_request().send().promise.await;
+                game_side = challenge_side;
+                join(&game_side, &events).await?;
+                continue;
+            }
+        };
+        match command {
+            NetworkCommand::Move(done_move) => {
+                let mut request = game_side.move_request();
+                done_move.to_builder(request.get().init_move());
+                if let Err(err) = request.send().promise.await {
+                    events.send(NetworkEvent::MoveRejected(err.to_string()))?;
+                }
+            }
+            NetworkCommand::RequestTakeback => {
+                let request = game_side.request_takeback_request();
+                let events = events.clone();
+                // The opponent takes their time to answer, the other commands go through meanwhile
+                transport::spawn_local(async move {
+                    let response = request.send().promise.await;
+                    let _ = events.send(
+                        match response.and_then(|response| Ok(response.get()?.get_accepted())) {
+                            Ok(accepted) => NetworkEvent::TakebackAnswered(accepted),
+                            Err(err) => NetworkEvent::TakebackRejected(err.to_string()),
+                        },
+                    );
+                });
+            }
+            NetworkCommand::Chat(message) => {
+                let mut request = game_side.chat_request();
+                request.get().set_message(message.as_str().into());
+                if let Err(err) = request.send().promise.await {
+                    events.send(NetworkEvent::ChatRejected(err.to_string()))?;
+                }
+            }
+            NetworkCommand::Action(action) => match send_action(&game_side, action).await {
+                Ok(Some(result)) => events.send(NetworkEvent::GameEnded(result))?,
+                Ok(None) => {}
+                Err(err) => events.send(NetworkEvent::ActionRejected(action, err.to_string()))?,
+            },
+            NetworkCommand::Resync => {
+                let id = game_side.id_request().send().promise.await?.get()?.get_id();
+                let moves = fetch_moves(&game_maker, id).await?;
+                events.send(NetworkEvent::Resynced(moves))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Send an action to the server, returns how the game ended if it did.
+async fn send_action(
+    game_side: &game_side::Client,
+    action: GameAction,
+) -> capnp::Result<Option<GameResult>> {
+    Ok(match action {
+        GameAction::OfferDraw => {
+            game_side.offer_draw_request().send().promise.await?;
+            None
+        }
+        GameAction::AcceptDraw => {
+            let response = game_side.accept_draw_request().send().promise.await?;
+            Some(read_result(response.get()?.get_result()?)?)
+        }
+        GameAction::DeclineDraw => {
+            game_side.decline_draw_request().send().promise.await?;
+            None
+        }
+        GameAction::Resign => {
+            let response = game_side.resign_request().send().promise.await?;
+            Some(read_result(response.get()?.get_result()?)?)
+        }
+        GameAction::Abort => {
+            let response = game_side.abort_request().send().promise.await?;
+            Some(read_result(response.get()?.get_result()?)?)
+        }
+    })
+}
+
+/// Follow a game without playing it, until the connection is lost.
+async fn watch_online(
+    server: ServerConfig,
+    id: u64,
+    mut commands: mpsc::UnboundedReceiver<NetworkCommand>,
+    events: std::sync::mpsc::Sender<NetworkEvent>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let game_maker = connect(&server).await?;
+    let directory = game_maker
+        .directory_request()
+        .send()
+        .promise
+        .await?
+        .get()?
+        .get_directory()?;
+
+    let mut request = directory.watch_request();
+    request.get().set_id(id);
+    request
+        .get()
+        .set_spectator(capnp_rpc::new_client(SpectatorImpl {
+            events: events.clone(),
+        }));
+    let response = request.send().promise.await?;
+    // Catch up with the moves played before joining
+    for done_move in response.get()?.get_moves()?.iter() {
+        events.send(NetworkEvent::Move(WireMove::from_reader(done_move)?))?;
+    }
+
+    // Live moves come through the spectator, keep the connection open until the game is closed
+    while let Some(command) = commands.next().await {
+        // Spectators only ask for the moves again
+        if let NetworkCommand::Resync = command {
+            let moves = fetch_moves(&game_maker, id).await?;
+            events.send(NetworkEvent::Resynced(moves))?;
+        }
+    }
+    Ok(())
+}
+
+/// The moves played so far in a game, to rebuild the board from.
+async fn fetch_moves(game_maker: &game_maker::Client, id: u64) -> capnp::Result<Vec<WireMove>> {
+    let directory = game_maker
+        .directory_request()
+        .send()
+        .promise
+        .await?
+        .get()?
+        .get_directory()?;
+    let mut request = directory.watch_request();
+    request.get().set_id(id);
+    request
+        .get()
+        .set_spectator(capnp_rpc::new_client(MoveListSpectator));
+    let response = request.send().promise.await?;
+    let moves = response.get()?.get_moves()?;
+    moves.iter().map(WireMove::from_reader).collect()
+}
+
+/// Spectator of a game whose moves are fetched once. It refuses the moves that follow, so the
+/// server forgets it.
+struct MoveListSpectator;
+
+impl spectator::Server for MoveListSpectator {}
+
+/// Receives the moves of a watched game from the server.
+struct SpectatorImpl {
+    events: std::sync::mpsc::Sender<NetworkEvent>,
+}
+
+impl spectator::Server for SpectatorImpl {
+    fn move_(
+        &mut self,
+        params: spectator::MoveParams,
+        _: spectator::MoveResults,
+    ) -> Promise<(), capnp::Error> {
+        let done_move = pry!(WireMove::from_reader(pry!(pry!(params.get()).get_move())));
+        pry!(self
+            .events
+            .send(NetworkEvent::Move(done_move))
+            .map_err(|err| capnp::Error::failed(err.to_string())));
+        Promise::ok(())
+    }
+
+    fn takeback(
+        &mut self,
+        _: spectator::TakebackParams,
+        _: spectator::TakebackResults,
+    ) -> Promise<(), capnp::Error> {
+        pry!(self
+            .events
+            .send(NetworkEvent::TakenBack)
+            .map_err(|err| capnp::Error::failed(err.to_string())));
+        Promise::ok(())
+    }
+
+    fn game_ended(
+        &mut self,
+        params: spectator::GameEndedParams,
+        _: spectator::GameEndedResults,
+    ) -> Promise<(), capnp::Error> {
+        let result = pry!(read_result(pry!(pry!(params.get()).get_result())));
+        pry!(self
+            .events
+            .send(NetworkEvent::GameEnded(result))
+            .map_err(|err| capnp::Error::failed(err.to_string())));
+        Promise::ok(())
+    }
+}
+
+/// Receives news about the friends of the local player from the server.
+struct SocialListenerImpl {
+    events: std::sync::mpsc::Sender<NetworkEvent>,
+    /// Where the sides of the games of accepted challenges go
+    accepted: mpsc::UnboundedSender<game_side::Client>,
+}
+
+impl SocialListenerImpl {
+    fn send(&self, news: String) -> Promise<(), capnp::Error> {
+        pry!(self
+            .events
+            .send(NetworkEvent::Social(news))
+            .map_err(|err| capnp::Error::failed(err.to_string())));
+        Promise::ok(())
+    }
+}
+
+impl social_listener::Server for SocialListenerImpl {
+    fn friend_request(
+        &mut self,
+        params: social_listener::FriendRequestParams,
+        _: social_listener::FriendRequestResults,
+    ) -> Promise<(), capnp::Error> {
+        let from = pry!(pry!(pry!(params.get()).get_from()).to_str());
+        self.send(format!("{from} asks to become friends"))
+    }
+
+    fn friend_request_accepted(
+        &mut self,
+        params: social_listener::FriendRequestAcceptedParams,
+        _: social_listener::FriendRequestAcceptedResults,
+    ) -> Promise<(), capnp::Error> {
+        let username = pry!(pry!(pry!(params.get()).get_username()).to_str());
+        self.send(format!("{username} is now your friend"))
+    }
+
+    fn presence(
+        &mut self,
+        params: social_listener::PresenceParams,
+        _: social_listener::PresenceResults,
+    ) -> Promise<(), capnp::Error> {
+        let params = pry!(params.get());
+        let username = pry!(pry!(params.get_username()).to_str());
+        if params.get_online() {
+            self.send(format!("{username} is online"))
+        } else {
+            self.send(format!("{username} left"))
+        }
+    }
+
+    fn challenged(
+        &mut self,
+        params: social_listener::ChallengedParams,
+        _: social_listener::ChallengedResults,
+    ) -> Promise<(), capnp::Error> {
+        let params = pry!(params.get());
+        let from = pry!(pry!(params.get_from()).to_str());
+        let rated = pry!(params.get_game_config()).get_rated();
+        let text = if rated {
+            format!("{from} challenges you to a rated game")
+        } else {
+            format!("{from} challenges you to a game")
+        };
+        let challenge = pry!(params.get_challenge());
+        let (answer, answered) = oneshot::channel();
+        pry!(self
+            .events
+            .send(NetworkEvent::Challenged(text, answer))
+            .map_err(|err| capnp::Error::failed(err.to_string())));
+
+        let events = self.events.clone();
+        let accepted = self.accepted.clone();
+        Promise::from_future(async move {
+            // Dropping the answer declines
+            if !answered.await.unwrap_or(false) {
+                challenge.decline_request().send().promise.await?;
+                return Ok(());
+            }
+            let mut request = challenge.accept_request();
+            request.get().set_player(capnp_rpc::new_client(PlayerImpl {
+                events: events.clone(),
+            }));
+            match request.send().promise.await {
+                Ok(response) => {
+                    let _ = accepted.unbounded_send(response.get()?.get_game_side()?);
+                }
+                Err(err) => {
+                    let _ = events.send(NetworkEvent::Social(format!(
+                        "Could not accept the challenge: {err}"
+                    )));
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Receives what the opponent does from the server.
+struct PlayerImpl {
+    events: std::sync::mpsc::Sender<NetworkEvent>,
+}
+
+impl player::Server for PlayerImpl {
+    fn move_(
+        &mut self,
+        params: player::MoveParams,
+        _: player::MoveResults,
+    ) -> Promise<(), capnp::Error> {
+        let done_move = pry!(WireMove::from_reader(pry!(pry!(params.get()).get_move())));
+        pry!(self
+            .events
+            .send(NetworkEvent::Move(done_move))
+            .map_err(|err| capnp::Error::failed(err.to_string())));
+        Promise::ok(())
+    }
+
+    fn takeback_requested(
+        &mut self,
+        _: player::TakebackRequestedParams,
+        mut results: player::TakebackRequestedResults,
+    ) -> Promise<(), capnp::Error> {
+        let (answer, answered) = oneshot::channel();
+        pry!(self
+            .events
+            .send(NetworkEvent::TakebackRequested(answer))
+            .map_err(|err| capnp::Error::failed(err.to_string())));
+        Promise::from_future(async move {
+            // Dropping the answer declines
+            let accept = answered.await.unwrap_or(false);
+            results.get().set_accept(accept);
+            Ok(())
+        })
+    }
+
+    fn chat_message(
+        &mut self,
+        params: player::ChatMessageParams,
+        _: player::ChatMessageResults,
+    ) -> Promise<(), capnp::Error> {
+        let params = pry!(params.get());
+        let from = pry!(pry!(params.get_from()).to_str()).to_string();
+        let message = pry!(pry!(params.get_message()).to_str()).to_string();
+        pry!(self
+            .events
+            .send(NetworkEvent::Chat { from, message })
+            .map_err(|err| capnp::Error::failed(err.to_string())));
+        Promise::ok(())
+    }
+
+    fn draw_offered(
+        &mut self,
+        _: player::DrawOfferedParams,
+        _: player::DrawOfferedResults,
+    ) -> Promise<(), capnp::Error> {
+        pry!(self
+            .events
+            .send(NetworkEvent::DrawOffered)
+            .map_err(|err| capnp::Error::failed(err.to_string())));
+        Promise::ok(())
+    }
+
+    fn draw_declined(
+        &mut self,
+        _: player::DrawDeclinedParams,
+        _: player::DrawDeclinedResults,
+    ) -> Promise<(), capnp::Error> {
+        pry!(self
+            .events
+            .send(NetworkEvent::DrawDeclined)
+            .map_err(|err| capnp::Error::failed(err.to_string())));
+        Promise::ok(())
+    }
+
+    fn game_ended(
+        &mut self,
+        params: player::GameEndedParams,
+        _: player::GameEndedResults,
+    ) -> Promise<(), capnp::Error> {
+        let result = pry!(read_result(pry!(pry!(params.get()).get_result())));
+        pry!(self
+            .events
+            .send(NetworkEvent::GameEnded(result))
+            .map_err(|err| capnp::Error::failed(err.to_string())));
+        Promise::ok(())
+    }
+}
+
+impl<'a> FromReader<'a> for Square {
+    type Reader = square::Reader<'a>;
+
+    fn from_reader(reader: square::Reader<'a>) -> capnp::Result<Self> {
+        let (x, y) = <(u8, u8)>::from_reader(reader)?;
+        Ok(Square { x, y })
+    }
+}
+
+impl<'a> ToBuilder<'a> for Square {
+    type Builder = square::Builder<'a>;
+
+    fn to_builder(&self, builder: square::Builder<'a>) {
+        (self.x, self.y).to_builder(builder);
+    }
+}
+
+/// A move as it is sent on the wire, played by the piece on `from`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct WireMove {
+    from: Square,
+    to: Square,
+    promotion: Option<PieceType>,
+    /// Number of moves played before this one
+    number: u32,
+}
+
+impl WireMove {
+    fn new(done_move: &Move, number: u32) -> Self {
+        WireMove {
+            from: Square {
+                x: done_move.piece.x,
+                y: done_move.piece.y,
+            },
+            to: done_move.square,
+            promotion: done_move.promotion,
+            number,
+        }
+    }
+
+    /// The move in a position, `None` if no piece stands on `from`.
+    fn to_move(self, pieces: &[Piece]) -> Option<Move> {
+        let piece = pieces
+            .iter()
+            .find(|piece| piece.x == self.from.x && piece.y == self.from.y)?;
+        Some(Move {
+            piece: *piece,
+            square: self.to,
+            promotion: self.promotion,
+        })
+    }
+}
+
+impl<'a> FromReader<'a> for WireMove {
+    type Reader = move_::Reader<'a>;
+
+    fn from_reader(reader: move_::Reader<'a>) -> capnp::Result<Self> {
+        Ok(WireMove {
+            from: Square::from_reader(reader.get_from()?)?,
+            to: Square::from_reader(reader.get_to()?)?,
+            promotion: match reader.get_promotion().which()? {
+                move_::promotion::None(()) => None,
+                move_::promotion::Piece(piece_type) => Some(piece_type?.into()),
+            },
+            number: reader.get_number(),
+        })
+    }
+}
+
+impl<'a> ToBuilder<'a> for WireMove {
+    type Builder = move_::Builder<'a>;
+
+    fn to_builder(&self, mut builder: move_::Builder<'a>) {
+        self.from.to_builder(builder.reborrow().init_from());
+        self.to.to_builder(builder.reborrow().init_to());
+        match self.promotion {
+            Some(piece_type) => builder
+                .reborrow()
+                .init_promotion()
+                .set_piece(piece_type.into()),
+            None => builder.reborrow().init_promotion().set_none(()),
+        }
+        builder.set_number(self.number);
+    }
+}
+
+fn read_result(reader: game_result::Reader) -> capnp::Result<GameResult> {
+    let winner = match reader.get_outcome()? {
+        game_result::Outcome::WhiteWins => Some(PieceColor::White),
+        game_result::Outcome::BlackWins => Some(PieceColor::Black),
+        game_result::Outcome::Draw | game_result::Outcome::NoResult => None,
+    };
+    Ok(match (reader.get_reason()?, winner) {
+        (game_result::Reason::Resignation, Some(winner)) => GameResult::Resignation { winner },
+        // Games ended by taking the king before the server knew about checkmate
+        (game_result::Reason::Checkmate | game_result::Reason::KingCaptured, Some(winner)) => {
+            GameResult::Checkmate { winner }
+        }
+        (game_result::Reason::Abandoned, Some(winner)) => GameResult::Abandoned { winner },
+        (
+            game_result::Reason::Resignation
+            | game_result::Reason::KingCaptured
+            | game_result::Reason::Checkmate
+            | game_result::Reason::Abandoned,
+            None,
+        ) => {
+            return Err(capnp::Error::failed(
+                "A game won without a winner".to_string(),
+            ))
+        }
+        (game_result::Reason::DrawAgreed, _) => GameResult::DrawAgreed,
+        (game_result::Reason::Aborted, _) => GameResult::Aborted,
+        (game_result::Reason::Stalemate, _) => GameResult::Stalemate,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn piece_color() -> impl Strategy<Value = PieceColor> {
+        prop_oneof![Just(PieceColor::White), Just(PieceColor::Black)]
+    }
+
+    fn piece_type() -> impl Strategy<Value = PieceType> {
+        prop_oneof![
+            Just(PieceType::King),
+            Just(PieceType::Queen),
+            Just(PieceType::Bishop),
+            Just(PieceType::Knight),
+            Just(PieceType::Rook),
+            Just(PieceType::Pawn),
+        ]
+    }
+
+    fn square() -> impl Strategy<Value = Square> {
+        (0..8u8, 0..8u8).prop_map(|(x, y)| Square { x, y })
+    }
+
+    fn piece() -> impl Strategy<Value = Piece> {
+        (piece_color(), piece_type(), square()).prop_map(|(color, piece_type, square)| Piece {
+            color,
+            piece_type,
+            x: square.x,
+            y: square.y,
+        })
+    }
+
+    fn game_move() -> impl Strategy<Value = Move> {
+        (piece(), square(), proptest::option::of(piece_type())).prop_map(
+            |(piece, square, promotion)| Move {
+                piece,
+                square,
+                promotion,
+            },
+        )
+    }
+
+    /// Write a move into a message and read it back.
+    fn through_wire(wire_move: &WireMove) -> WireMove {
+        let mut message = capnp::message::Builder::new_default();
+        wire_move.to_builder(message.init_root::<move_::Builder>());
+        let reader = message.get_root_as_reader::<move_::Reader>().unwrap();
+        WireMove::from_reader(reader).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn move_round_trips(
+            done_move in game_move(),
+            number in any::<u32>(),
+            others in proptest::collection::vec(piece(), 0..32),
+        ) {
+            // The piece played is found among the others on the board
+            let mut pieces: Vec<Piece> = others
+                .into_iter()
+                .filter(|piece| piece.x != done_move.piece.x || piece.y != done_move.piece.y)
+                .collect();
+            pieces.push(done_move.piece);
+
+            let wire_move = through_wire(&WireMove::new(&done_move, number));
+            prop_assert_eq!(wire_move.number, number);
+            prop_assert_eq!(wire_move.to_move(&pieces), Some(done_move));
+        }
+
+        #[test]
+        fn wire_move_round_trips(
+            from in square(),
+            to in square(),
+            promotion in proptest::option::of(piece_type()),
+            number in any::<u32>(),
+        ) {
+            let wire_move = WireMove {
+                from,
+                to,
+                promotion,
+                number,
+            };
+            prop_assert_eq!(through_wire(&wire_move), wire_move);
+        }
+    }
+}
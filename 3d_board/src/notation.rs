@@ -0,0 +1,629 @@
+use crate::movement::{Move, Piece, PieceColor, PieceType, PlayerTurn, Square};
+use fluffy_chess_protocol::rules::{Board, Ending};
+use std::fmt;
+
+/// Name of a square in algebraic notation, e.g. `e4`.
+pub fn square_name(x: u8, y: u8) -> String {
+    format!("{}{}", (b'a' + y) as char, x + 1)
+}
+
+fn parse_square(file: char, rank: char) -> Option<(u8, u8)> {
+    if ('a'..='h').contains(&file) && ('1'..='8').contains(&rank) {
+        Some((rank as u8 - b'1', file as u8 - b'a'))
+    } else {
+        None
+    }
+}
+
+fn piece_type_of_letter(letter: char) -> Option<PieceType> {
+    match letter {
+        'K' => Some(PieceType::King),
+        'Q' => Some(PieceType::Queen),
+        'B' => Some(PieceType::Bishop),
+        'N' => Some(PieceType::Knight),
+        'R' => Some(PieceType::Rook),
+        _ => None,
+    }
+}
+
+fn piece_letter(piece_type: PieceType) -> Option<char> {
+    match piece_type {
+        PieceType::King => Some('K'),
+        PieceType::Queen => Some('Q'),
+        PieceType::Bishop => Some('B'),
+        PieceType::Knight => Some('N'),
+        PieceType::Rook => Some('R'),
+        PieceType::Pawn => None,
+    }
+}
+
+/// Standard algebraic notation of a move, given the position it was played from.
+pub fn san(done_move: &Move, before: &Board) -> String {
+    let piece = done_move.piece;
+    let from = (piece.x, piece.y);
+    let target = (done_move.square.x, done_move.square.y);
+    // A pawn moving diagonally to an empty square takes en passant
+    let capture = before.get(target).is_some()
+        || (piece.piece_type == PieceType::Pawn && piece.y != target.1);
+
+    let mut san = String::new();
+    if done_move.castling_rook().is_some() {
+        san.push_str(if target.1 > piece.y { "O-O" } else { "O-O-O" });
+    } else {
+        match piece_letter(piece.piece_type) {
+            None => {
+                // Pawn captures are prefixed with the file they leave from
+                if capture {
+                    san.push((b'a' + piece.y) as char);
+                }
+            }
+            Some(letter) => {
+                san.push(letter);
+                // Disambiguate when another piece of the same kind can reach the target
+                let rivals: Vec<(u8, u8)> = before
+                    .pieces()
+                    .filter(|&(square, (color, piece_type))| {
+                        PieceType::from(piece_type) == piece.piece_type
+                            && PieceColor::from(color) == piece.color
+                            && square != from
+                            && before.is_legal(square, target)
+                    })
+                    .map(|(square, _)| square)
+                    .collect();
+                if !rivals.is_empty() {
+                    if rivals.iter().all(|other| other.1 != piece.y) {
+                        san.push((b'a' + piece.y) as char);
+                    } else if rivals.iter().all(|other| other.0 != piece.x) {
+                        san.push((b'1' + piece.x) as char);
+                    } else {
+                        san.push_str(&square_name(piece.x, piece.y));
+                    }
+                }
+            }
+        }
+        if capture {
+            san.push('x');
+        }
+        san.push_str(&square_name(target.0, target.1));
+        if let Some(promotion) = done_move.promotion.and_then(piece_letter) {
+            san.push('=');
+            san.push(promotion);
+        }
+    }
+
+    let mut after = before.clone();
+    after.apply(from, target, done_move.promotion.map(Into::into));
+    let opponent = piece.color.opposite().into();
+    if after.ending(opponent) == Some(Ending::Checkmate) {
+        san.push('#');
+    } else if after.in_check(opponent) {
+        san.push('+');
+    }
+    san
+}
+
+/// A move typed by a player, resolved against the position it is played in.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TypedMove {
+    pub from: (u8, u8),
+    pub to: (u8, u8),
+    pub promotion: Option<PieceType>,
+}
+
+/// Why a typed move can't be played.
+#[derive(Clone, PartialEq, Debug)]
+pub enum MoveInputError {
+    /// The text is neither SAN nor UCI notation
+    Unreadable,
+    /// The side to move has no piece on the square the move starts from
+    NoPiece(String),
+    /// No piece of the side to move can play the move
+    Illegal,
+    /// Several pieces can play the move, on these squares
+    Ambiguous(Vec<String>),
+    /// The move is marked as a capture, but there is nothing to take on the square
+    NothingToTake(String),
+    /// Only a pawn reaching the last rank is promoted, to a queen, a rook, a bishop or a knight
+    Promotion,
+}
+
+impl fmt::Display for MoveInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveInputError::Unreadable => {
+                write!(f, "not a move, type it like e2e4, Nf3, O-O or e8=Q")
+            }
+            MoveInputError::NoPiece(square) => write!(f, "you have no piece on {square}"),
+            MoveInputError::Illegal => write!(f, "this move can't be played in this position"),
+            MoveInputError::Ambiguous(squares) => write!(
+                f,
+                "ambiguous, the pieces on {} can all play it, add the file or rank to move from",
+                squares.join(" and ")
+            ),
+            MoveInputError::NothingToTake(square) => {
+                write!(f, "there is nothing to take on {square}")
+            }
+            MoveInputError::Promotion => write!(
+                f,
+                "only a pawn reaching the last rank is promoted, to Q, R, B or N"
+            ),
+        }
+    }
+}
+
+/// Parse a move typed in SAN (`Nf3`, `exd5`, `O-O`, `e8=Q`) or UCI (`e2e4`, `e7e8q`) notation,
+/// for the side to move.
+pub fn parse_move(
+    input: &str,
+    pieces: &[Piece],
+    turn: &PlayerTurn,
+) -> Result<TypedMove, MoveInputError> {
+    // Check and annotation marks don't change the move
+    let text = input.trim().trim_end_matches(['+', '#', '!', '?']);
+    let chars: Vec<char> = text.chars().collect();
+
+    let typed_move = match parse_castling(text, pieces, turn).or_else(|| parse_uci(&chars)) {
+        Some(typed_move) => typed_move,
+        None => parse_san(&chars, pieces, turn)?,
+    };
+
+    let piece = find_piece(typed_move.from, pieces, turn).ok_or_else(|| {
+        MoveInputError::NoPiece(square_name(typed_move.from.0, typed_move.from.1))
+    })?;
+    if !piece.is_legal(typed_move.to, pieces, turn) {
+        return Err(MoveInputError::Illegal);
+    }
+    let promoting =
+        piece.piece_type == PieceType::Pawn && (typed_move.to.0 == 0 || typed_move.to.0 == 7);
+    match typed_move.promotion {
+        Some(PieceType::King | PieceType::Pawn) => return Err(MoveInputError::Promotion),
+        Some(_) if !promoting => return Err(MoveInputError::Promotion),
+        _ => {}
+    }
+    Ok(typed_move)
+}
+
+/// The piece of the side to move on a square.
+fn find_piece(position: (u8, u8), pieces: &[Piece], turn: &PlayerTurn) -> Option<Piece> {
+    pieces
+        .iter()
+        .find(|piece| piece.x == position.0 && piece.y == position.1 && piece.color == turn.color)
+        .copied()
+}
+
+fn parse_castling(text: &str, pieces: &[Piece], turn: &PlayerTurn) -> Option<TypedMove> {
+    // Zeros are common in place of the letter O
+    let king_side = match text.to_uppercase().replace('0', "O").as_str() {
+        "O-O" => true,
+        "O-O-O" => false,
+        _ => return None,
+    };
+    let king = pieces
+        .iter()
+        .find(|piece| piece.piece_type == PieceType::King && piece.color == turn.color)?;
+    Some(TypedMove {
+        from: (king.x, king.y),
+        to: (
+            king.x,
+            if king_side {
+                king.y + 2
+            } else {
+                king.y.saturating_sub(2)
+            },
+        ),
+        promotion: None,
+    })
+}
+
+/// Long algebraic notation, the squares the piece moves between and the promotion in lowercase.
+fn parse_uci(chars: &[char]) -> Option<TypedMove> {
+    let (squares, promotion) = match chars {
+        [a, b, c, d] => ([*a, *b, *c, *d], None),
+        [a, b, c, d, promotion] => (
+            [*a, *b, *c, *d],
+            Some(piece_type_of_letter(promotion.to_ascii_uppercase())?),
+        ),
+        _ => return None,
+    };
+    Some(TypedMove {
+        from: parse_square(squares[0], squares[1])?,
+        to: parse_square(squares[2], squares[3])?,
+        promotion,
+    })
+}
+
+fn parse_san(
+    chars: &[char],
+    pieces: &[Piece],
+    turn: &PlayerTurn,
+) -> Result<TypedMove, MoveInputError> {
+    let mut chars = chars;
+
+    // Promotion at the end, as in `e8=Q` or `e8Q`
+    let mut promotion = None;
+    if let [rest @ .., last] = chars {
+        if let Some(piece_type) = piece_type_of_letter(last.to_ascii_uppercase()) {
+            match rest {
+                [rest @ .., '='] => {
+                    promotion = Some(piece_type);
+                    chars = rest;
+                }
+                [.., rank] if rank.is_ascii_digit() && last.is_ascii_uppercase() => {
+                    promotion = Some(piece_type);
+                    chars = rest;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Target square
+    let [rest @ .., file, rank] = chars else {
+        return Err(MoveInputError::Unreadable);
+    };
+    let target = parse_square(*file, *rank).ok_or(MoveInputError::Unreadable)?;
+    chars = rest;
+
+    // Piece letter, pawns have none
+    let mut piece_type = PieceType::Pawn;
+    if let [letter, rest @ ..] = chars {
+        if let Some(letter_type) = piece_type_of_letter(*letter) {
+            piece_type = letter_type;
+            chars = rest;
+        }
+    }
+
+    let mut capture = false;
+    if let [rest @ .., 'x' | ':'] = chars {
+        capture = true;
+        chars = rest;
+    }
+
+    // What is left tells apart pieces that can reach the same square
+    let (from_file, from_rank) = match chars {
+        [] => (None, None),
+        [file @ 'a'..='h'] => (Some(*file as u8 - b'a'), None),
+        [rank @ '1'..='8'] => (None, Some(*rank as u8 - b'1')),
+        [file @ 'a'..='h', rank @ '1'..='8'] => {
+            (Some(*file as u8 - b'a'), Some(*rank as u8 - b'1'))
+        }
+        _ => return Err(MoveInputError::Unreadable),
+    };
+
+    let candidates: Vec<&Piece> = pieces
+        .iter()
+        .filter(|piece| {
+            piece.color == turn.color
+                && piece.piece_type == piece_type
+                && (from_file.is_none() || from_file == Some(piece.y))
+                && (from_rank.is_none() || from_rank == Some(piece.x))
+                && piece.is_legal(target, pieces, turn)
+        })
+        .collect();
+    let piece = match candidates[..] {
+        [] => return Err(MoveInputError::Illegal),
+        [piece] => piece,
+        _ => {
+            return Err(MoveInputError::Ambiguous(
+                candidates
+                    .iter()
+                    .map(|piece| square_name(piece.x, piece.y))
+                    .collect(),
+            ))
+        }
+    };
+
+    let found_move = Move {
+        piece: *piece,
+        square: Square {
+            x: target.0,
+            y: target.1,
+        },
+        promotion: None,
+    };
+    if capture && found_move.captured_square(pieces).is_none() {
+        return Err(MoveInputError::NothingToTake(square_name(
+            target.0, target.1,
+        )));
+    }
+    Ok(TypedMove {
+        from: (piece.x, piece.y),
+        to: target,
+        promotion,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::movement::PieceColor::{Black, White};
+    use crate::movement::PieceType::{Bishop, King, Knight, Pawn, Queen, Rook};
+    use fluffy_chess_protocol::fluffy_chess_capnp::{piece, Color};
+    use fluffy_chess_protocol::rules::{self, square};
+
+    /// Pieces standing on the named squares, as the board keeps them.
+    fn position(pieces: &[(&str, PieceColor, PieceType)]) -> Vec<Piece> {
+        pieces
+            .iter()
+            .map(|&(name, color, piece_type)| {
+                let (x, y) = square(name);
+                Piece {
+                    color,
+                    piece_type,
+                    x,
+                    y,
+                }
+            })
+            .collect()
+    }
+
+    fn typed(
+        from: &str,
+        to: &str,
+        promotion: Option<PieceType>,
+    ) -> Result<TypedMove, MoveInputError> {
+        Ok(TypedMove {
+            from: square(from),
+            to: square(to),
+            promotion,
+        })
+    }
+
+    /// Parse each input for `color` in a position, and compare with the expected move.
+    fn check(
+        pieces: &[Piece],
+        turn: &PlayerTurn,
+        cases: &[(&str, Result<TypedMove, MoveInputError>)],
+    ) {
+        for (input, expected) in cases {
+            assert_eq!(&parse_move(input, pieces, turn), expected, "{input}");
+        }
+    }
+
+    fn turn(color: PieceColor) -> PlayerTurn {
+        let mut turn = PlayerTurn::default();
+        turn.color = color;
+        turn
+    }
+
+    #[test]
+    fn disambiguation() {
+        let knights = position(&[
+            ("e1", White, King),
+            ("b1", White, Knight),
+            ("f3", White, Knight),
+            ("e8", Black, King),
+        ]);
+        check(
+            &knights,
+            &turn(White),
+            &[
+                ("Nbd2", typed("b1", "d2", None)),
+                ("Nfd2", typed("f3", "d2", None)),
+                ("N1d2", typed("b1", "d2", None)),
+                ("Nb1d2", typed("b1", "d2", None)),
+                ("Nc3", typed("b1", "c3", None)),
+                ("Nd4", typed("f3", "d4", None)),
+                (
+                    "Nd2",
+                    Err(MoveInputError::Ambiguous(vec!["b1".into(), "f3".into()])),
+                ),
+            ],
+        );
+
+        let rooks = position(&[
+            ("h1", White, King),
+            ("e1", White, Rook),
+            ("e3", White, Rook),
+            ("h8", Black, King),
+            ("e5", Black, Pawn),
+        ]);
+        check(
+            &rooks,
+            &turn(White),
+            &[
+                ("R1e2", typed("e1", "e2", None)),
+                ("R3e2", typed("e3", "e2", None)),
+                (
+                    "Re2",
+                    Err(MoveInputError::Ambiguous(vec!["e1".into(), "e3".into()])),
+                ),
+                // The rook on e1 is blocked by the one on e3
+                ("Re4", typed("e3", "e4", None)),
+            ],
+        );
+    }
+
+    #[test]
+    fn castling() {
+        let pieces = position(&[
+            ("e1", White, King),
+            ("a1", White, Rook),
+            ("h1", White, Rook),
+            ("e8", Black, King),
+            ("h8", Black, Rook),
+        ]);
+        check(
+            &pieces,
+            &turn(White),
+            &[
+                ("O-O", typed("e1", "g1", None)),
+                ("O-O-O", typed("e1", "c1", None)),
+                ("0-0", typed("e1", "g1", None)),
+                ("o-o-o+", typed("e1", "c1", None)),
+            ],
+        );
+        check(
+            &pieces,
+            &turn(Black),
+            &[
+                ("O-O", typed("e8", "g8", None)),
+                ("O-O-O", Err(MoveInputError::Illegal)),
+            ],
+        );
+
+        let mut moved = turn(White);
+        moved.castling.white_king_side = false;
+        check(&pieces, &moved, &[("O-O", Err(MoveInputError::Illegal))]);
+
+        let mut blocked = pieces.clone();
+        blocked.extend(position(&[("b1", White, Knight)]));
+        check(
+            &blocked,
+            &turn(White),
+            &[("O-O-O", Err(MoveInputError::Illegal))],
+        );
+    }
+
+    #[test]
+    fn promotion() {
+        let pieces = position(&[
+            ("a1", White, King),
+            ("e7", White, Pawn),
+            ("d4", White, Pawn),
+            ("h8", Black, King),
+            ("f8", Black, Rook),
+        ]);
+        check(
+            &pieces,
+            &turn(White),
+            &[
+                ("e8=Q", typed("e7", "e8", Some(Queen))),
+                ("e8N", typed("e7", "e8", Some(Knight))),
+                ("e8=B+", typed("e7", "e8", Some(Bishop))),
+                ("exf8=R", typed("e7", "f8", Some(Rook))),
+                ("e7e8q", typed("e7", "e8", Some(Queen))),
+                // The piece is picked when the move is played
+                ("e8", typed("e7", "e8", None)),
+                ("e8=K", Err(MoveInputError::Promotion)),
+                ("d5=Q", Err(MoveInputError::Promotion)),
+                ("d4d5q", Err(MoveInputError::Promotion)),
+            ],
+        );
+    }
+
+    #[test]
+    fn captures() {
+        let pieces = position(&[
+            ("e1", White, King),
+            ("e3", White, Rook),
+            ("d4", White, Pawn),
+            ("e8", Black, King),
+            ("e5", Black, Pawn),
+        ]);
+        check(
+            &pieces,
+            &turn(White),
+            &[
+                ("Rxe5", typed("e3", "e5", None)),
+                ("dxe5", typed("d4", "e5", None)),
+                ("d4xe5", typed("d4", "e5", None)),
+                ("de5", typed("d4", "e5", None)),
+                ("Rxe4", Err(MoveInputError::NothingToTake("e4".into()))),
+                ("dxc5", Err(MoveInputError::Illegal)),
+            ],
+        );
+
+        // Black just played e7e5
+        let mut en_passant = turn(White);
+        en_passant.en_passant = Some(Square { x: 5, y: 4 });
+        let pieces = position(&[
+            ("e1", White, King),
+            ("d5", White, Pawn),
+            ("e8", Black, King),
+            ("e5", Black, Pawn),
+        ]);
+        check(&pieces, &en_passant, &[("dxe6", typed("d5", "e6", None))]);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let pieces = position(&[
+            ("e1", White, King),
+            ("b1", White, Knight),
+            ("e8", Black, King),
+        ]);
+        check(
+            &pieces,
+            &turn(White),
+            &[
+                ("", Err(MoveInputError::Unreadable)),
+                ("hello", Err(MoveInputError::Unreadable)),
+                ("Nz3", Err(MoveInputError::Unreadable)),
+                ("e2e9", Err(MoveInputError::Unreadable)),
+                ("O-O-O-O", Err(MoveInputError::Unreadable)),
+                ("Qd4", Err(MoveInputError::Illegal)),
+                ("Ke3", Err(MoveInputError::Illegal)),
+                ("e3e4", Err(MoveInputError::NoPiece("e3".into()))),
+                ("e8e7", Err(MoveInputError::NoPiece("e8".into()))),
+            ],
+        );
+
+        // The knight is pinned to its king
+        let pinned = position(&[
+            ("e1", White, King),
+            ("e2", White, Knight),
+            ("e8", Black, King),
+            ("e7", Black, Rook),
+        ]);
+        check(
+            &pinned,
+            &turn(White),
+            &[
+                ("Nc3", Err(MoveInputError::Illegal)),
+                ("Kd1", typed("e1", "d1", None)),
+            ],
+        );
+    }
+
+    /// Notation of the move between two squares, in a position written with the shared helpers.
+    fn san_of(board: &Board, from: &str, to: &str, promotion: Option<PieceType>) -> String {
+        let (color, piece_type) = board.get(square(from)).unwrap();
+        let (x, y) = square(from);
+        let (to_x, to_y) = square(to);
+        let done_move = Move {
+            piece: Piece {
+                color: color.into(),
+                piece_type: piece_type.into(),
+                x,
+                y,
+            },
+            square: Square { x: to_x, y: to_y },
+            promotion,
+        };
+        san(&done_move, board)
+    }
+
+    #[test]
+    fn notation_of_moves() {
+        let mut board = Board::default();
+        assert_eq!(san_of(&board, "g1", "f3", None), "Nf3");
+        for (from, to) in [("f2", "f3"), ("e7", "e5"), ("g2", "g4")] {
+            board.apply(square(from), square(to), None);
+        }
+        assert_eq!(san_of(&board, "d8", "h4", None), "Qh4#");
+
+        let board = rules::position(&[
+            ("e1", Color::White, piece::Type::King),
+            ("a1", Color::White, piece::Type::Rook),
+            ("b1", Color::White, piece::Type::Knight),
+            ("f3", Color::White, piece::Type::Knight),
+            ("e7", Color::White, piece::Type::Pawn),
+            ("e8", Color::Black, piece::Type::King),
+            ("d8", Color::Black, piece::Type::Rook),
+        ]);
+        assert_eq!(san_of(&board, "b1", "d2", None), "Nbd2");
+        assert_eq!(san_of(&board, "a1", "a8", None), "Ra8");
+        assert_eq!(san_of(&board, "e7", "d8", Some(Queen)), "exd8=Q+");
+
+        // The pawn that just moved two squares is taken en passant
+        let mut board = rules::position(&[
+            ("e1", Color::White, piece::Type::King),
+            ("e5", Color::White, piece::Type::Pawn),
+            ("e8", Color::Black, piece::Type::King),
+            ("d5", Color::Black, piece::Type::Pawn),
+        ]);
+        board.allow_en_passant(square("d6"));
+        assert_eq!(san_of(&board, "e5", "d6", None), "exd6");
+    }
+}
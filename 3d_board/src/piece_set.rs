@@ -0,0 +1,208 @@
+use crate::movement::{PieceColor, PieceType};
+use crate::settings::Settings;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext};
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use serde::Deserialize;
+use std::f32::consts::PI;
+use std::fmt;
+
+/// Piece sets that can be picked in the settings, by name and asset path.
+pub const PIECE_SETS: [(&str, &str); 2] = [
+    ("Chess kit", "pieces/chess_kit.pieces.ron"),
+    ("Chess kit, small", "pieces/chess_kit_small.pieces.ron"),
+];
+
+pub struct PieceSetPlugin;
+impl Plugin for PieceSetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<PieceSet>()
+            .init_asset_loader::<PieceSetLoader>()
+            .init_resource::<PieceSets>();
+    }
+}
+
+/// Piece set manifest, as written in a `.pieces.ron` file.
+#[derive(Deserialize)]
+struct PieceSetManifest {
+    /// Path of the glTF file holding the meshes
+    model: String,
+    /// Scale of every piece, unless the piece has its own
+    scale: f32,
+    /// Turn the black knights around, to face the white pieces
+    #[serde(default)]
+    knights_face_opponent: bool,
+    king: PieceManifest,
+    queen: PieceManifest,
+    bishop: PieceManifest,
+    knight: PieceManifest,
+    rook: PieceManifest,
+    pawn: PieceManifest,
+}
+
+#[derive(Deserialize)]
+struct PieceManifest {
+    /// Labels of the glTF primitives the piece is made of, like `Mesh0/Primitive0`
+    primitives: Vec<String>,
+    /// Moves the meshes to the middle of the square
+    #[serde(default)]
+    offset: [f32; 3],
+    /// Turn around the vertical axis, in degrees
+    #[serde(default)]
+    rotation: f32,
+    #[serde(default)]
+    scale: Option<f32>,
+}
+
+/// Meshes of one kind of piece, and where they are placed on their square.
+pub struct PieceMeshes {
+    pub meshes: Vec<Handle<Mesh>>,
+    pub transform: Transform,
+}
+
+/// The models of every kind of piece, loaded from a `.pieces.ron` manifest.
+#[derive(Asset, TypePath)]
+pub struct PieceSet {
+    knights_face_opponent: bool,
+    king: PieceMeshes,
+    queen: PieceMeshes,
+    bishop: PieceMeshes,
+    knight: PieceMeshes,
+    rook: PieceMeshes,
+    pawn: PieceMeshes,
+}
+
+impl PieceSet {
+    pub fn model(&self, piece_type: PieceType) -> &PieceMeshes {
+        match piece_type {
+            PieceType::King => &self.king,
+            PieceType::Queen => &self.queen,
+            PieceType::Bishop => &self.bishop,
+            PieceType::Knight => &self.knight,
+            PieceType::Rook => &self.rook,
+            PieceType::Pawn => &self.pawn,
+        }
+    }
+
+    /// Transform of the meshes of a piece, relative to its square.
+    pub fn transform(&self, piece_type: PieceType, color: PieceColor) -> Transform {
+        let transform = self.model(piece_type).transform;
+        if self.knights_face_opponent
+            && piece_type == PieceType::Knight
+            && color == PieceColor::Black
+        {
+            Transform::from_rotation(Quat::from_rotation_y(PI)) * transform
+        } else {
+            transform
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct PieceSetLoader;
+
+#[derive(Debug)]
+pub enum PieceSetLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl fmt::Display for PieceSetLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PieceSetLoaderError::Io(err) => write!(f, "could not read piece set: {err}"),
+            PieceSetLoaderError::Ron(err) => write!(f, "invalid piece set: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PieceSetLoaderError {}
+
+impl AssetLoader for PieceSetLoader {
+    type Asset = PieceSet;
+    type Settings = ();
+    type Error = PieceSetLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<PieceSet, PieceSetLoaderError>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(PieceSetLoaderError::Io)?;
+            let manifest: PieceSetManifest =
+                ron::de::from_bytes(&bytes).map_err(PieceSetLoaderError::Ron)?;
+
+            let mut load_model = |piece: &PieceManifest| PieceMeshes {
+                meshes: piece
+                    .primitives
+                    .iter()
+                    .map(|primitive| load_context.load(format!("{}#{primitive}", manifest.model)))
+                    .collect(),
+                // Turn around the middle of the square, not around the origin of the mesh
+                transform: Transform::from_rotation(Quat::from_rotation_y(
+                    piece.rotation.to_radians(),
+                )) * Transform::from_translation(Vec3::from_array(piece.offset))
+                    .with_scale(Vec3::splat(piece.scale.unwrap_or(manifest.scale))),
+            };
+            Ok(PieceSet {
+                knights_face_opponent: manifest.knights_face_opponent,
+                king: load_model(&manifest.king),
+                queen: load_model(&manifest.queen),
+                bishop: load_model(&manifest.bishop),
+                knight: load_model(&manifest.knight),
+                rook: load_model(&manifest.rook),
+                pawn: load_model(&manifest.pawn),
+            })
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["pieces.ron"]
+    }
+}
+
+/// Every piece set that can be picked, and the one in use.
+#[derive(Resource)]
+pub struct PieceSets {
+    handles: Vec<Handle<PieceSet>>,
+    current: usize,
+}
+
+impl PieceSets {
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn current_id(&self) -> AssetId<PieceSet> {
+        self.handles[self.current].id()
+    }
+
+    pub fn select(&mut self, index: usize) {
+        if index < self.handles.len() {
+            self.current = index;
+        }
+    }
+}
+
+impl FromWorld for PieceSets {
+    fn from_world(world: &mut World) -> Self {
+        let current = world
+            .get_resource::<Settings>()
+            .map_or(0, |settings| settings.piece_set_index());
+        let asset_server = world.resource::<AssetServer>();
+        Self {
+            handles: PIECE_SETS
+                .iter()
+                .map(|(_, path)| asset_server.load(*path))
+                .collect(),
+            current,
+        }
+    }
+}
@@ -1,212 +1,168 @@
+use crate::animation::Animations;
 use crate::board::{SelectedPiece, SelectedSquare};
-use crate::movement::{AttemptMove, Piece, PieceColor, PieceType, PlayerTurn, Square};
-use bevy::math::vec4;
+use crate::camera::MainCamera;
+use crate::history::MoveHistory;
+use crate::movement::{AttemptMove, Captured, Piece, PieceColor, PieceType, PlayerTurn, Square};
+use crate::network::{can_play, OnlineGame};
+use crate::piece_set::{PieceSet, PieceSets};
+use crate::theme::ThemeMaterials;
 use bevy::prelude::*;
 use bevy_mod_picking::prelude::*;
 
-const HIGHLIGHT_TINT: Highlight<StandardMaterial> = Highlight {
-    hovered: Some(HighlightKind::new_dynamic(|matl| StandardMaterial {
-        base_color: matl.base_color + vec4(-0.2, -0.2, 0.4, 0.0),
-        ..matl.to_owned()
-    })),
-    pressed: Some(HighlightKind::new_dynamic(|matl| StandardMaterial {
-        base_color: matl.base_color + vec4(-0.3, -0.3, 0.5, 0.0),
-        ..matl.to_owned()
-    })),
-    selected: Some(HighlightKind::new_dynamic(|matl| StandardMaterial {
-        base_color: matl.base_color + vec4(-0.3, 0.2, -0.3, 0.0),
-        ..matl.to_owned()
-    })),
-};
-
 pub struct PiecesPlugin;
 impl Plugin for PiecesPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, create_pieces)
-            .add_systems(Update, move_pieces);
+            .add_systems(Update, update_piece_models);
     }
 }
 
-fn create_pieces(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
-    // Load all the meshes
-    let king_handle: Handle<Mesh> =
-        asset_server.load("models/chess_kit/pieces.glb#Mesh0/Primitive0");
-    let king_cross_handle: Handle<Mesh> =
-        asset_server.load("models/chess_kit/pieces.glb#Mesh1/Primitive0");
-    let pawn_handle: Handle<Mesh> =
-        asset_server.load("models/chess_kit/pieces.glb#Mesh2/Primitive0");
-    let knight_1_handle: Handle<Mesh> =
-        asset_server.load("models/chess_kit/pieces.glb#Mesh3/Primitive0");
-    let knight_2_handle: Handle<Mesh> =
-        asset_server.load("models/chess_kit/pieces.glb#Mesh4/Primitive0");
-    let rook_handle: Handle<Mesh> =
-        asset_server.load("models/chess_kit/pieces.glb#Mesh5/Primitive0");
-    let bishop_handle: Handle<Mesh> =
-        asset_server.load("models/chess_kit/pieces.glb#Mesh6/Primitive0");
-    let queen_handle: Handle<Mesh> =
-        asset_server.load("models/chess_kit/pieces.glb#Mesh7/Primitive0");
-
-    // Add some materials
-    let white_material = materials.add(Color::rgb(1., 0.8, 0.8).into());
-    let black_material = materials.add(Color::rgb(0., 0.2, 0.2).into());
+const BACK_RANK: [PieceType; 8] = [
+    PieceType::Rook,
+    PieceType::Knight,
+    PieceType::Bishop,
+    PieceType::Queen,
+    PieceType::King,
+    PieceType::Bishop,
+    PieceType::Knight,
+    PieceType::Rook,
+];
 
+fn create_pieces(mut commands: Commands) {
     let pieces_parent = commands.spawn((PbrBundle::default(),)).id();
 
-    let white_pieces = [
-        spawn_rook(
-            &mut commands,
-            white_material.clone(),
-            PieceColor::White,
-            rook_handle.clone(),
-            (0, 0),
-        ),
-        spawn_knight(
-            &mut commands,
-            white_material.clone(),
-            PieceColor::White,
-            knight_1_handle.clone(),
-            knight_2_handle.clone(),
-            (0, 1),
-        ),
-        spawn_bishop(
-            &mut commands,
-            white_material.clone(),
-            PieceColor::White,
-            bishop_handle.clone(),
-            (0, 2),
-        ),
-        spawn_queen(
-            &mut commands,
-            white_material.clone(),
-            PieceColor::White,
-            queen_handle.clone(),
-            (0, 3),
-        ),
-        spawn_king(
-            &mut commands,
-            white_material.clone(),
-            PieceColor::White,
-            king_handle.clone(),
-            king_cross_handle.clone(),
-            (0, 4),
-        ),
-        spawn_bishop(
-            &mut commands,
-            white_material.clone(),
-            PieceColor::White,
-            bishop_handle.clone(),
-            (0, 5),
-        ),
-        spawn_knight(
-            &mut commands,
-            white_material.clone(),
-            PieceColor::White,
-            knight_1_handle.clone(),
-            knight_2_handle.clone(),
-            (0, 6),
-        ),
-        spawn_rook(
-            &mut commands,
-            white_material.clone(),
-            PieceColor::White,
-            rook_handle.clone(),
-            (0, 7),
-        ),
-    ];
-    commands.entity(pieces_parent).push_children(&white_pieces);
+    for (color, back_rank, pawn_rank) in [(PieceColor::White, 0, 1), (PieceColor::Black, 7, 6)] {
+        for (y, piece_type) in BACK_RANK.into_iter().enumerate() {
+            let piece = spawn_piece(
+                &mut commands,
+                Piece {
+                    color,
+                    piece_type,
+                    x: back_rank,
+                    y: y as u8,
+                },
+            );
+            commands.entity(pieces_parent).add_child(piece);
+        }
+        for y in 0..8u8 {
+            let pawn = spawn_piece(
+                &mut commands,
+                Piece {
+                    color,
+                    piece_type: PieceType::Pawn,
+                    x: pawn_rank,
+                    y,
+                },
+            );
+            commands.entity(pieces_parent).add_child(pawn);
+        }
+    }
+}
+
+/// Spawn a playable piece that can be selected and moved, or dragged to its new square.
+///
+/// Its meshes are added by `update_piece_models` once the piece set is loaded.
+pub(crate) fn spawn_piece(commands: &mut Commands, piece: Piece) -> Entity {
+    commands
+        .spawn((
+            PbrBundle {
+                transform: Transform::from_translation(Vec3::new(
+                    piece.x as f32,
+                    0.,
+                    piece.y as f32,
+                )),
+                ..Default::default()
+            },
+            piece,
+            PieceModel(None),
+            On::<Pointer<Select>>::run(select),
+            On::<Pointer<DragStart>>::run(drag_start),
+            On::<Pointer<Drag>>::run(drag_piece),
+            On::<Pointer<DragEnd>>::run(drag_end),
+            On::<Pointer<Drop>>::run(drop_piece),
+        ))
+        .id()
+}
+
+/// Marks the piece being dragged, which follows the cursor instead of going to its square.
+#[derive(Component)]
+pub(crate) struct Dragged;
 
-    let mut white_pawns = [pieces_parent; 8];
+/// Spawn only the meshes of a piece, without any game state attached to it.
+pub(crate) fn spawn_piece_model(
+    commands: &mut Commands,
+    piece_set: &PieceSet,
+    theme: &ThemeMaterials,
+    piece: &Piece,
+) -> Entity {
+    commands
+        // Spawn parent entity
+        .spawn(PbrBundle {
+            transform: Transform::from_translation(Vec3::new(piece.x as f32, 0., piece.y as f32)),
+            ..Default::default()
+        })
+        // Add the meshes as children of the parent
+        .with_children(|parent| spawn_piece_meshes(parent, piece_set, theme, piece))
+        .id()
+}
 
-    for i in 0..8u8 {
-        white_pawns[i as usize] = spawn_pawn(
-            &mut commands,
-            white_material.clone(),
-            PieceColor::White,
-            pawn_handle.clone(),
-            (1, i),
-        );
+/// Spawn the meshes of a piece as given by the piece set, in the materials of its color.
+fn spawn_piece_meshes(
+    parent: &mut ChildBuilder,
+    piece_set: &PieceSet,
+    theme: &ThemeMaterials,
+    piece: &Piece,
+) {
+    let materials = theme.pieces(piece.color);
+    let transform = piece_set.transform(piece.piece_type, piece.color);
+    for mesh in piece_set.model(piece.piece_type).meshes.iter() {
+        parent.spawn((
+            PbrBundle {
+                mesh: mesh.clone(),
+                material: materials.base.clone(),
+                transform,
+                ..Default::default()
+            },
+            PickableBundle::default(),
+            materials.highlight(),
+        ));
     }
-    commands.entity(pieces_parent).push_children(&white_pawns);
+}
 
-    let black_pieces = [
-        spawn_rook(
-            &mut commands,
-            black_material.clone(),
-            PieceColor::Black,
-            rook_handle.clone(),
-            (7, 0),
-        ),
-        spawn_knight(
-            &mut commands,
-            black_material.clone(),
-            PieceColor::Black,
-            knight_1_handle.clone(),
-            knight_2_handle.clone(),
-            (7, 1),
-        ),
-        spawn_bishop(
-            &mut commands,
-            black_material.clone(),
-            PieceColor::Black,
-            bishop_handle.clone(),
-            (7, 2),
-        ),
-        spawn_queen(
-            &mut commands,
-            black_material.clone(),
-            PieceColor::Black,
-            queen_handle.clone(),
-            (7, 3),
-        ),
-        spawn_king(
-            &mut commands,
-            black_material.clone(),
-            PieceColor::Black,
-            king_handle.clone(),
-            king_cross_handle.clone(),
-            (7, 4),
-        ),
-        spawn_bishop(
-            &mut commands,
-            black_material.clone(),
-            PieceColor::Black,
-            bishop_handle.clone(),
-            (7, 5),
-        ),
-        spawn_knight(
-            &mut commands,
-            black_material.clone(),
-            PieceColor::Black,
-            knight_1_handle.clone(),
-            knight_2_handle.clone(),
-            (7, 6),
-        ),
-        spawn_rook(
-            &mut commands,
-            black_material.clone(),
-            PieceColor::Black,
-            rook_handle.clone(),
-            (7, 7),
-        ),
-    ];
+/// Kind of piece shown by the meshes of a playable piece, `None` until they are spawned.
+#[derive(Component)]
+struct PieceModel(Option<PieceType>);
 
-    commands.entity(pieces_parent).push_children(&black_pieces);
+/// Swap the meshes of pieces whose kind changed, when a pawn is promoted or the promotion taken
+/// back, and of every piece when another piece set is picked or its file changes.
+fn update_piece_models(
+    mut commands: Commands,
+    mut events: EventReader<AssetEvent<PieceSet>>,
+    piece_sets: Res<PieceSets>,
+    piece_set_assets: Res<Assets<PieceSet>>,
+    theme: Res<ThemeMaterials>,
+    mut query: Query<(Entity, &Piece, &mut PieceModel)>,
+) {
+    let set_changed = events.read().any(|event| match event {
+        AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => {
+            *id == piece_sets.current_id()
+        }
+        _ => false,
+    }) || piece_sets.is_changed();
+    let Some(piece_set) = piece_set_assets.get(piece_sets.current_id()) else {
+        return;
+    };
 
-    let mut black_pawns = [pieces_parent; 8];
-    for i in 0..8u8 {
-        black_pawns[i as usize] = spawn_pawn(
-            &mut commands,
-            black_material.clone(),
-            PieceColor::Black,
-            pawn_handle.clone(),
-            (6, i),
-        );
+    for (entity, piece, mut model) in query.iter_mut() {
+        if !set_changed && model.0 == Some(piece.piece_type) {
+            continue;
+        }
+        model.0 = Some(piece.piece_type);
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|parent| spawn_piece_meshes(parent, piece_set, &theme, piece));
     }
-    commands.entity(pieces_parent).push_children(&black_pawns);
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -215,10 +171,16 @@ fn select(
     mut selected_square: ResMut<SelectedSquare>,
     mut selected_piece: ResMut<SelectedPiece>,
     turn: Res<PlayerTurn>,
+    history: Res<MoveHistory>,
+    online: Option<Res<OnlineGame>>,
+    animations: Res<Animations>,
     mut attempt_move: EventWriter<AttemptMove>,
-    pieces_query: Query<(Entity, &mut Piece)>,
+    pieces_query: Query<(Entity, &mut Piece), Without<Captured>>,
     squares_query: Query<(Entity, &Square)>,
 ) {
+    if !history.is_live() || !can_play(&turn, online.as_deref()) || !animations.is_idle() {
+        return;
+    }
     match selected_piece.entity {
         None => {
             if pieces_query
@@ -244,6 +206,7 @@ fn select(
             attempt_move.send(AttemptMove {
                 piece: selected_piece_entity,
                 square,
+                promotion: None,
             });
             selected_piece.entity = None;
             selected_square.entity = None;
@@ -251,312 +214,107 @@ fn select(
     }
 }
 
-fn spawn_king(
-    commands: &mut Commands,
-    material: Handle<StandardMaterial>,
-    piece_color: PieceColor,
-    mesh: Handle<Mesh>,
-    mesh_cross: Handle<Mesh>,
-    position: (u8, u8),
-) -> Entity {
-    commands
-        // Spawn parent entity
-        .spawn((
-            PbrBundle {
-                transform: Transform::from_translation(Vec3::new(
-                    position.0 as f32,
-                    0.,
-                    position.1 as f32,
-                )),
-                ..Default::default()
-            },
-            On::<Pointer<Select>>::run(select),
-            Piece {
-                color: piece_color,
-                piece_type: PieceType::King,
-                x: position.0,
-                y: position.1,
-            },
-        ))
-        // Add children to the parent
-        .with_children(|parent| {
-            parent.spawn((
-                PbrBundle {
-                    mesh,
-                    material: material.clone(),
-                    transform: {
-                        let mut transform = Transform::from_translation(Vec3::new(-0.2, 0., -1.9));
-                        transform.scale *= Vec3::new(0.2, 0.2, 0.2);
-                        transform
-                    },
-                    ..Default::default()
-                },
-                PickableBundle::default(),
-                HIGHLIGHT_TINT,
-            ));
-            parent.spawn((
-                PbrBundle {
-                    mesh: mesh_cross,
-                    material,
-                    transform: {
-                        let mut transform = Transform::from_translation(Vec3::new(-0.2, 0., -1.9));
-                        transform.scale *= Vec3::new(0.2, 0.2, 0.2);
-                        transform
-                    },
-                    ..Default::default()
-                },
-                PickableBundle::default(),
-                HIGHLIGHT_TINT,
-            ));
-        })
-        .id()
-}
-
-fn spawn_knight(
-    commands: &mut Commands,
-    material: Handle<StandardMaterial>,
-    piece_color: PieceColor,
-    mesh_1: Handle<Mesh>,
-    mesh_2: Handle<Mesh>,
-    position: (u8, u8),
-) -> Entity {
-    commands
-        // Spawn parent entity
-        .spawn((
-            PbrBundle {
-                transform: Transform::from_translation(Vec3::new(
-                    position.0 as f32,
-                    0.,
-                    position.1 as f32,
-                )),
-                ..Default::default()
-            },
-            On::<Pointer<Select>>::run(select),
-            Piece {
-                color: piece_color,
-                piece_type: PieceType::Knight,
-                x: position.0,
-                y: position.1,
-            },
-        ))
-        // Add children to the parent
-        .with_children(|parent| {
-            parent.spawn((
-                PbrBundle {
-                    mesh: mesh_1,
-                    material: material.clone(),
-                    transform: {
-                        let mut transform = Transform::from_translation(Vec3::new(-0.2, 0., 0.9));
-                        transform.scale *= Vec3::new(0.2, 0.2, 0.2);
-                        transform
-                    },
-                    ..Default::default()
-                },
-                PickableBundle::default(),
-                HIGHLIGHT_TINT,
-            ));
-            parent.spawn((
-                PbrBundle {
-                    mesh: mesh_2,
-                    material,
-                    transform: {
-                        let mut transform = Transform::from_translation(Vec3::new(-0.2, 0., 0.9));
-                        transform.scale *= Vec3::new(0.2, 0.2, 0.2);
-                        transform
-                    },
-                    ..Default::default()
-                },
-                PickableBundle::default(),
-                HIGHLIGHT_TINT,
-            ));
-        })
-        .id()
-}
-
-fn spawn_queen(
-    commands: &mut Commands,
-    material: Handle<StandardMaterial>,
-    piece_color: PieceColor,
-    mesh: Handle<Mesh>,
-    position: (u8, u8),
-) -> Entity {
-    commands
-        .spawn((
-            PbrBundle {
-                transform: Transform::from_translation(Vec3::new(
-                    position.0 as f32,
-                    0.,
-                    position.1 as f32,
-                )),
-                ..Default::default()
-            },
-            On::<Pointer<Select>>::run(select),
-            Piece {
-                color: piece_color,
-                piece_type: PieceType::Queen,
-                x: position.0,
-                y: position.1,
-            },
-        ))
-        .with_children(|parent| {
-            parent.spawn((
-                PbrBundle {
-                    mesh,
-                    material,
-                    transform: {
-                        let mut transform = Transform::from_translation(Vec3::new(-0.2, 0., -0.95));
-                        transform.scale *= Vec3::new(0.2, 0.2, 0.2);
-                        transform
-                    },
-                    ..Default::default()
-                },
-                PickableBundle::default(),
-                HIGHLIGHT_TINT,
-            ));
-        })
-        .id()
-}
-
-fn spawn_bishop(
-    commands: &mut Commands,
-    material: Handle<StandardMaterial>,
-    piece_color: PieceColor,
-    mesh: Handle<Mesh>,
-    position: (u8, u8),
-) -> Entity {
-    commands
-        .spawn((
-            PbrBundle {
-                transform: Transform::from_translation(Vec3::new(
-                    position.0 as f32,
-                    0.,
-                    position.1 as f32,
-                )),
-                ..Default::default()
-            },
-            On::<Pointer<Select>>::run(select),
-            Piece {
-                color: piece_color,
-                piece_type: PieceType::Bishop,
-                x: position.0,
-                y: position.1,
-            },
-        ))
-        .with_children(|parent| {
-            parent.spawn((
-                PbrBundle {
-                    mesh,
-                    material,
-                    transform: {
-                        let mut transform = Transform::from_translation(Vec3::new(-0.1, 0., 0.));
-                        transform.scale *= Vec3::new(0.2, 0.2, 0.2);
-                        transform
-                    },
-                    ..Default::default()
-                },
-                PickableBundle::default(),
-                HIGHLIGHT_TINT,
-            ));
-        })
-        .id()
+fn drag_start(
+    listener: Listener<Pointer<DragStart>>,
+    mut commands: Commands,
+    turn: Res<PlayerTurn>,
+    history: Res<MoveHistory>,
+    online: Option<Res<OnlineGame>>,
+    animations: Res<Animations>,
+    pieces_query: Query<(&Piece, &Children), Without<Captured>>,
+) {
+    // The other buttons turn the camera around the board
+    if listener.button != PointerButton::Primary {
+        return;
+    }
+    if !history.is_live() || !can_play(&turn, online.as_deref()) || !animations.is_idle() {
+        return;
+    }
+    let Ok((piece, children)) = pieces_query.get(listener.listener()) else {
+        return;
+    };
+    if piece.color != turn.color {
+        return;
+    }
+    commands.entity(listener.listener()).insert(Dragged);
+    // Let the pointer reach what is under the piece, to know where it is dropped
+    for child in children.iter() {
+        commands.entity(*child).insert(Pickable::IGNORE);
+    }
 }
 
-fn spawn_rook(
-    commands: &mut Commands,
-    material: Handle<StandardMaterial>,
-    piece_color: PieceColor,
-    mesh: Handle<Mesh>,
-    position: (u8, u8),
-) -> Entity {
-    commands
-        .spawn((
-            PbrBundle {
-                transform: Transform::from_translation(Vec3::new(
-                    position.0 as f32,
-                    0.,
-                    position.1 as f32,
-                )),
-                ..Default::default()
-            },
-            On::<Pointer<Select>>::run(select),
-            Piece {
-                color: piece_color,
-                piece_type: PieceType::Rook,
-                x: position.0,
-                y: position.1,
-            },
-        ))
-        .with_children(|parent| {
-            parent.spawn((
-                PbrBundle {
-                    mesh,
-                    material,
-                    transform: {
-                        let mut transform = Transform::from_translation(Vec3::new(-0.1, 0., 1.8));
-                        transform.scale *= Vec3::new(0.2, 0.2, 0.2);
-                        transform
-                    },
-                    ..Default::default()
-                },
-                PickableBundle::default(),
-                HIGHLIGHT_TINT,
-            ));
-        })
-        .id()
+/// Follow the cursor on the plane of the board
+fn drag_piece(
+    listener: Listener<Pointer<Drag>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut pieces_query: Query<&mut Transform, With<Dragged>>,
+) {
+    let Ok(mut transform) = pieces_query.get_mut(listener.listener()) else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = camera_query.get_single() else {
+        return;
+    };
+    let Some(ray) = camera.viewport_to_world(camera_transform, listener.pointer_location.position)
+    else {
+        return;
+    };
+    let Some(distance) = ray.intersect_plane(Vec3::ZERO, Vec3::Y) else {
+        return;
+    };
+    transform.translation = ray.get_point(distance);
 }
 
-fn spawn_pawn(
-    commands: &mut Commands,
-    material: Handle<StandardMaterial>,
-    piece_color: PieceColor,
-    mesh: Handle<Mesh>,
-    position: (u8, u8),
-) -> Entity {
-    commands
-        .spawn((
-            PbrBundle {
-                transform: Transform::from_translation(Vec3::new(
-                    position.0 as f32,
-                    0.,
-                    position.1 as f32,
-                )),
-                ..Default::default()
-            },
-            On::<Pointer<Select>>::run(select),
-            Piece {
-                color: piece_color,
-                piece_type: PieceType::Pawn,
-                x: position.0,
-                y: position.1,
-            },
-        ))
-        .with_children(|parent| {
-            parent.spawn((
-                PbrBundle {
-                    mesh,
-                    material,
-                    transform: {
-                        let mut transform = Transform::from_translation(Vec3::new(-0.2, 0., 2.6));
-                        transform.scale *= Vec3::new(0.2, 0.2, 0.2);
-                        transform
-                    },
-                    ..Default::default()
-                },
-                PickableBundle::default(),
-                HIGHLIGHT_TINT,
-            ));
-        })
-        .id()
+/// Release the piece, it is animated to its square whether it moved or not
+fn drag_end(
+    listener: Listener<Pointer<DragEnd>>,
+    mut commands: Commands,
+    pieces_query: Query<&Children, With<Dragged>>,
+) {
+    let Ok(children) = pieces_query.get(listener.listener()) else {
+        return;
+    };
+    commands.entity(listener.listener()).remove::<Dragged>();
+    for child in children.iter() {
+        commands.entity(*child).insert(Pickable::default());
+    }
 }
 
-fn move_pieces(time: Res<Time>, mut query: Query<(&mut Transform, &Piece)>) {
-    for (mut transform, piece) in query.iter_mut() {
-        // Get the direction to move in
-        let direction = Vec3::new(piece.x as f32, 0., piece.y as f32) - transform.translation;
-
-        // Only move if the piece isn't already there (distance is big)
-        if direction.length() > 0.1 {
-            transform.translation += direction.normalize() * 8. * time.delta_seconds();
-        }
+/// Move the dragged piece to the square of the piece it is dropped on
+#[allow(clippy::too_many_arguments)]
+fn drop_piece(
+    listener: Listener<Pointer<Drop>>,
+    mut selected_square: ResMut<SelectedSquare>,
+    mut selected_piece: ResMut<SelectedPiece>,
+    history: Res<MoveHistory>,
+    mut attempt_move: EventWriter<AttemptMove>,
+    dragged_query: Query<Entity, With<Dragged>>,
+    pieces_query: Query<&Piece, Without<Captured>>,
+    squares_query: Query<(Entity, &Square)>,
+) {
+    let Ok(dragged) = dragged_query.get_single() else {
+        return;
+    };
+    if !history.is_live() {
+        return;
     }
+    let Ok(piece) = pieces_query.get(listener.listener()) else {
+        return;
+    };
+    let Some(square) = squares_query.iter().find_map(|(entity, square)| {
+        if piece.x == square.x && piece.y == square.y {
+            Some(entity)
+        } else {
+            None
+        }
+    }) else {
+        return;
+    };
+    attempt_move.send(AttemptMove {
+        piece: dragged,
+        square,
+        promotion: None,
+    });
+    selected_piece.entity = None;
+    selected_square.entity = None;
 }
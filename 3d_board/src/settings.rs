@@ -0,0 +1,250 @@
+use crate::camera::{CameraSettings, CameraView};
+use crate::piece_set::{PieceSets, PIECE_SETS};
+use crate::sound::{SoundPacks, SOUND_PACKS};
+use crate::theme::{Themes, THEMES};
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowResolution};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How long the settings stay the same before they are saved, so resizing the window or dragging
+/// a slider writes them once
+const SAVE_DELAY: Duration = Duration::from_secs(1);
+
+pub struct SettingsPlugin;
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (record_settings, apply_msaa).chain())
+            // Last, to see the exit asked during the update
+            .add_systems(Last, save_settings);
+    }
+}
+
+/// Everything the player can set that is kept from one run to the next, in `settings.ron` in the
+/// config directory of the platform, or in the local storage of the browser.
+///
+/// It is loaded in `main` before the app is built, so the other plugins can read it when they
+/// create their own resources.
+#[derive(Resource, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct Settings {
+    /// Size of the window, in logical pixels
+    pub window_width: f32,
+    pub window_height: f32,
+    /// Samples per pixel for antialiasing: 1 for none, 2, 4 or 8
+    pub msaa_samples: u32,
+    /// Name of one of the THEMES
+    pub theme: String,
+    /// Name of one of the PIECE_SETS
+    pub piece_set: String,
+    pub auto_flip: bool,
+    pub camera_view: CameraView,
+    /// Volume of the sound effects, from 0 to 1
+    pub volume: f32,
+    pub muted: bool,
+    /// Name of one of the SOUND_PACKS
+    pub sound_pack: String,
+    /// Game server to play on when none is given on the command line, or in the address of the
+    /// page in browsers
+    pub server: Option<String>,
+    /// Name to play as on the server when none is given on the command line or the address of the
+    /// page
+    pub user: String,
+    /// Players whose chat messages are hidden
+    pub muted_users: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            window_width: 800.,
+            window_height: 800.,
+            msaa_samples: 4,
+            theme: THEMES[0].0.to_string(),
+            piece_set: PIECE_SETS[0].0.to_string(),
+            auto_flip: true,
+            camera_view: CameraView::White,
+            volume: 0.8,
+            muted: false,
+            sound_pack: SOUND_PACKS[0].0.to_string(),
+            server: None,
+            user: "player".to_string(),
+            muted_users: Vec::new(),
+        }
+    }
+}
+
+impl Settings {
+    /// Read the stored settings, or use the defaults when there are none or they can't be read.
+    pub fn load() -> Self {
+        let Some(text) = storage::read() else {
+            return Self::default();
+        };
+        ron::from_str(&text).unwrap_or_else(|err| {
+            println!(
+                "Ignoring invalid settings in {}: {err}",
+                storage::location()
+            );
+            Self::default()
+        })
+    }
+
+    fn save(&self) {
+        let saved = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| err.to_string())
+            .and_then(|text| storage::write(&text));
+        if let Err(err) = saved {
+            println!(
+                "Could not save the settings to {}: {err}",
+                storage::location()
+            );
+        }
+    }
+
+    pub fn window_resolution(&self) -> WindowResolution {
+        WindowResolution::new(self.window_width, self.window_height)
+    }
+
+    pub fn msaa(&self) -> Msaa {
+        match self.msaa_samples {
+            0 | 1 => Msaa::Off,
+            2 => Msaa::Sample2,
+            8 => Msaa::Sample8,
+            _ => Msaa::Sample4,
+        }
+    }
+
+    /// Index of the theme in THEMES, the first one when it is not found.
+    pub fn theme_index(&self) -> usize {
+        THEMES
+            .iter()
+            .position(|(name, _)| *name == self.theme)
+            .unwrap_or(0)
+    }
+
+    /// Index of the piece set in PIECE_SETS, the first one when it is not found.
+    pub fn piece_set_index(&self) -> usize {
+        PIECE_SETS
+            .iter()
+            .position(|(name, _)| *name == self.piece_set)
+            .unwrap_or(0)
+    }
+
+    /// Index of the sound pack in SOUND_PACKS, the first one when it is not found.
+    pub fn sound_pack_index(&self) -> usize {
+        SOUND_PACKS
+            .iter()
+            .position(|(name, _)| *name == self.sound_pack)
+            .unwrap_or(0)
+    }
+}
+
+/// The settings file in the config directory of the platform.
+#[cfg(not(target_arch = "wasm32"))]
+mod storage {
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("fluffy_chess").join("settings.ron"))
+    }
+
+    pub fn location() -> String {
+        path().map_or_else(
+            || "the config directory".to_string(),
+            |path| path.display().to_string(),
+        )
+    }
+
+    pub fn read() -> Option<String> {
+        fs::read_to_string(path()?).ok()
+    }
+
+    pub fn write(text: &str) -> Result<(), String> {
+        // Nowhere to keep them
+        let Some(path) = path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+        }
+        fs::write(&path, text).map_err(|err| err.to_string())
+    }
+}
+
+/// An entry of the local storage of the browser, kept for the origin of the page.
+#[cfg(target_arch = "wasm32")]
+mod storage {
+    const KEY: &str = "fluffy_chess.settings";
+
+    fn local_storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok().flatten()
+    }
+
+    pub fn location() -> String {
+        format!("the local storage entry {KEY}")
+    }
+
+    pub fn read() -> Option<String> {
+        local_storage()?.get_item(KEY).ok().flatten()
+    }
+
+    pub fn write(text: &str) -> Result<(), String> {
+        // Private windows may have none
+        let Some(storage) = local_storage() else {
+            return Ok(());
+        };
+        storage
+            .set_item(KEY, text)
+            .map_err(|err| format!("{err:?}"))
+    }
+}
+
+/// Copy the choices made elsewhere in the app into the settings.
+fn record_settings(
+    mut settings: ResMut<Settings>,
+    themes: Res<Themes>,
+    piece_sets: Res<PieceSets>,
+    sound_packs: Res<SoundPacks>,
+    camera: Res<CameraSettings>,
+    window_query: Query<&Window, (With<PrimaryWindow>, Changed<Window>)>,
+) {
+    let mut recorded = settings.clone();
+    recorded.theme = THEMES[themes.current()].0.to_string();
+    recorded.piece_set = PIECE_SETS[piece_sets.current()].0.to_string();
+    recorded.sound_pack = SOUND_PACKS[sound_packs.current()].0.to_string();
+    recorded.auto_flip = camera.auto_flip;
+    recorded.camera_view = camera.view;
+    if let Ok(window) = window_query.get_single() {
+        recorded.window_width = window.width();
+        recorded.window_height = window.height();
+    }
+    settings.set_if_neq(recorded);
+}
+
+fn apply_msaa(settings: Res<Settings>, mut msaa: ResMut<Msaa>) {
+    if settings.is_changed() {
+        msaa.set_if_neq(settings.msaa());
+    }
+}
+
+/// Save the settings once they stopped changing for SAVE_DELAY, or when the app exits.
+fn save_settings(
+    settings: Res<Settings>,
+    time: Res<Time>,
+    mut exits: EventReader<AppExit>,
+    mut pending: Local<Option<Timer>>,
+) {
+    if settings.is_changed() && !settings.is_added() {
+        *pending = Some(Timer::new(SAVE_DELAY, TimerMode::Once));
+    }
+    let exiting = exits.read().next().is_some();
+    let Some(timer) = pending.as_mut() else {
+        return;
+    };
+    if timer.tick(time.delta()).finished() || exiting {
+        settings.save();
+        *pending = None;
+    }
+}
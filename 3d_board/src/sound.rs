@@ -0,0 +1,248 @@
+use crate::history::{record_moves, MoveHistory};
+use crate::movement::{Move, PlayerTurn};
+use crate::network::{can_play, OnlineGame};
+use crate::settings::Settings;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext};
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use serde::Deserialize;
+use std::fmt;
+use std::time::Duration;
+
+/// Sound packs that can be picked in the settings, by name and asset path.
+pub const SOUND_PACKS: [(&str, &str); 2] = [
+    ("Tones", "sounds/tones.sounds.ron"),
+    ("Soft", "sounds/soft.sounds.ron"),
+];
+
+/// Time left on the clock of the local player under which they are warned
+const LOW_TIME: Duration = Duration::from_secs(10);
+
+pub struct SoundPlugin;
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<SoundPack>()
+            .init_asset_loader::<SoundPackLoader>()
+            .init_resource::<SoundPacks>()
+            .add_event::<Sound>()
+            .add_systems(
+                Update,
+                (move_sounds.after(record_moves), low_time_sound, play_sounds).chain(),
+            );
+    }
+}
+
+/// Something that happened in the game and has a sound.
+#[derive(Event, Clone, Copy, PartialEq)]
+pub enum Sound {
+    Move,
+    Capture,
+    Castle,
+    Check,
+    Promotion,
+    /// The king was checkmated
+    GameOver,
+    /// The clock of the local player is running out
+    LowTime,
+}
+
+/// Sound pack manifest, as written in a `.sounds.ron` file: the path of the audio file of each
+/// sound.
+#[derive(Deserialize)]
+struct SoundPackManifest {
+    #[serde(rename = "move")]
+    quiet_move: String,
+    capture: String,
+    castle: String,
+    check: String,
+    promotion: String,
+    game_over: String,
+    low_time: String,
+}
+
+/// The audio of every sound, loaded from a `.sounds.ron` manifest.
+#[derive(Asset, TypePath)]
+pub struct SoundPack {
+    quiet_move: Handle<AudioSource>,
+    capture: Handle<AudioSource>,
+    castle: Handle<AudioSource>,
+    check: Handle<AudioSource>,
+    promotion: Handle<AudioSource>,
+    game_over: Handle<AudioSource>,
+    low_time: Handle<AudioSource>,
+}
+
+impl SoundPack {
+    pub fn audio(&self, sound: Sound) -> &Handle<AudioSource> {
+        match sound {
+            Sound::Move => &self.quiet_move,
+            Sound::Capture => &self.capture,
+            Sound::Castle => &self.castle,
+            Sound::Check => &self.check,
+            Sound::Promotion => &self.promotion,
+            Sound::GameOver => &self.game_over,
+            Sound::LowTime => &self.low_time,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct SoundPackLoader;
+
+#[derive(Debug)]
+pub enum SoundPackLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl fmt::Display for SoundPackLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SoundPackLoaderError::Io(err) => write!(f, "could not read sound pack: {err}"),
+            SoundPackLoaderError::Ron(err) => write!(f, "invalid sound pack: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SoundPackLoaderError {}
+
+impl AssetLoader for SoundPackLoader {
+    type Asset = SoundPack;
+    type Settings = ();
+    type Error = SoundPackLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<SoundPack, SoundPackLoaderError>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(SoundPackLoaderError::Io)?;
+            let manifest: SoundPackManifest =
+                ron::de::from_bytes(&bytes).map_err(SoundPackLoaderError::Ron)?;
+            Ok(SoundPack {
+                quiet_move: load_context.load(manifest.quiet_move),
+                capture: load_context.load(manifest.capture),
+                castle: load_context.load(manifest.castle),
+                check: load_context.load(manifest.check),
+                promotion: load_context.load(manifest.promotion),
+                game_over: load_context.load(manifest.game_over),
+                low_time: load_context.load(manifest.low_time),
+            })
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["sounds.ron"]
+    }
+}
+
+/// Every sound pack that can be picked, and the one in use.
+#[derive(Resource)]
+pub struct SoundPacks {
+    handles: Vec<Handle<SoundPack>>,
+    current: usize,
+}
+
+impl SoundPacks {
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn select(&mut self, index: usize) {
+        if index < self.handles.len() {
+            self.current = index;
+        }
+    }
+}
+
+impl FromWorld for SoundPacks {
+    fn from_world(world: &mut World) -> Self {
+        let current = world
+            .get_resource::<Settings>()
+            .map_or(0, |settings| settings.sound_pack_index());
+        let asset_server = world.resource::<AssetServer>();
+        Self {
+            handles: SOUND_PACKS
+                .iter()
+                .map(|(_, path)| asset_server.load(*path))
+                .collect(),
+            current,
+        }
+    }
+}
+
+/// Sound of a move, from its notation.
+fn sound_of_move(san: &str) -> Sound {
+    if san.ends_with('#') {
+        Sound::GameOver
+    } else if san.ends_with('+') {
+        Sound::Check
+    } else if san.contains('=') {
+        Sound::Promotion
+    } else if san.starts_with("O-O") {
+        Sound::Castle
+    } else if san.contains('x') {
+        Sound::Capture
+    } else {
+        Sound::Move
+    }
+}
+
+/// Pick the sound of each move played, once it is in the history.
+fn move_sounds(
+    history: Res<MoveHistory>,
+    mut moves: EventReader<Move>,
+    mut sounds: EventWriter<Sound>,
+) {
+    // The moves of this frame are the last ones recorded
+    let played = moves.read().count();
+    let records = history.records();
+    for record in &records[records.len().saturating_sub(played)..] {
+        sounds.send(sound_of_move(&record.san));
+    }
+}
+
+/// Warn once when the clock of the local player goes under LOW_TIME on their turn.
+fn low_time_sound(
+    turn: Res<PlayerTurn>,
+    online: Option<Res<OnlineGame>>,
+    mut sounds: EventWriter<Sound>,
+    mut warned: Local<bool>,
+) {
+    if !can_play(&turn, online.as_deref()) {
+        return;
+    }
+    let low = turn.time_left().is_some_and(|left| left < LOW_TIME);
+    if low && !*warned {
+        sounds.send(Sound::LowTime);
+    }
+    *warned = low;
+}
+
+fn play_sounds(
+    mut commands: Commands,
+    mut sounds: EventReader<Sound>,
+    settings: Res<Settings>,
+    sound_packs: Res<SoundPacks>,
+    sound_pack_assets: Res<Assets<SoundPack>>,
+) {
+    let pack = sound_pack_assets.get(&sound_packs.handles[sound_packs.current]);
+    let Some(pack) = pack.filter(|_| !settings.muted) else {
+        sounds.clear();
+        return;
+    };
+    for sound in sounds.read() {
+        commands.spawn(AudioBundle {
+            source: pack.audio(*sound).clone(),
+            settings: PlaybackSettings::DESPAWN.with_volume(Volume::new_relative(settings.volume)),
+        });
+    }
+}
@@ -0,0 +1,376 @@
+use crate::camera::MainCamera;
+use crate::movement::PieceColor;
+use crate::settings::Settings;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext};
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use bevy_mod_picking::prelude::*;
+use serde::Deserialize;
+use std::fmt;
+
+/// Themes that can be picked in the settings, by name and asset path.
+pub const THEMES: [(&str, &str); 3] = [
+    ("Classic", "themes/classic.theme.ron"),
+    ("Walnut", "themes/walnut.theme.ron"),
+    ("Marble", "themes/marble.theme.ron"),
+];
+
+pub struct ThemePlugin;
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<Theme>()
+            .init_asset_loader::<ThemeLoader>()
+            .init_resource::<ThemeMaterials>()
+            .init_resource::<Themes>()
+            .add_systems(Update, apply_theme);
+    }
+}
+
+/// Colors, materials and lighting of the board and pieces, loaded from a `.theme.ron` asset.
+#[derive(Asset, TypePath, Deserialize, Clone)]
+pub struct Theme {
+    pub light_square: [f32; 3],
+    pub dark_square: [f32; 3],
+    pub white_pieces: PieceMaterial,
+    pub black_pieces: PieceMaterial,
+    pub highlight: HighlightTints,
+    pub light: Lighting,
+    /// Image based lighting, from a pair of KTX2 cubemaps
+    #[serde(default)]
+    pub environment_map: Option<EnvironmentMap>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct PieceMaterial {
+    pub color: [f32; 3],
+    #[serde(default)]
+    pub metallic: f32,
+    #[serde(default = "default_roughness")]
+    pub roughness: f32,
+    /// Path of an image asset multiplied with the color
+    #[serde(default)]
+    pub texture: Option<String>,
+}
+
+fn default_roughness() -> f32 {
+    0.5
+}
+
+/// Added to the color of a square or piece when the pointer is over it, presses it or selects it.
+#[derive(Deserialize, Clone)]
+pub struct HighlightTints {
+    pub hovered: [f32; 3],
+    pub pressed: [f32; 3],
+    pub selected: [f32; 3],
+}
+
+#[derive(Deserialize, Clone)]
+pub struct Lighting {
+    pub color: [f32; 3],
+    pub intensity: f32,
+    pub position: [f32; 3],
+    /// Brightness of the light coming from everywhere
+    pub ambient: f32,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct EnvironmentMap {
+    pub diffuse: String,
+    pub specular: String,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            light_square: [1., 0.9, 0.9],
+            dark_square: [0., 0.1, 0.1],
+            white_pieces: PieceMaterial {
+                color: [1., 0.8, 0.8],
+                metallic: 0.,
+                roughness: default_roughness(),
+                texture: None,
+            },
+            black_pieces: PieceMaterial {
+                color: [0., 0.2, 0.2],
+                metallic: 0.,
+                roughness: default_roughness(),
+                texture: None,
+            },
+            highlight: HighlightTints {
+                hovered: [-0.2, -0.2, 0.4],
+                pressed: [-0.3, -0.3, 0.5],
+                selected: [-0.3, 0.2, -0.3],
+            },
+            light: Lighting {
+                color: [1., 1., 1.],
+                intensity: 800.,
+                position: [4., 8., 4.],
+                ambient: 0.05,
+            },
+            environment_map: None,
+        }
+    }
+}
+
+fn color([r, g, b]: [f32; 3]) -> Color {
+    Color::rgb(r, g, b)
+}
+
+/// Add a highlight tint to a color, leaving its alpha alone.
+fn tint(base: Color, [r, g, b]: [f32; 3]) -> Color {
+    (Vec4::from(base) + Vec4::new(r, g, b, 0.)).into()
+}
+
+#[derive(Default)]
+pub struct ThemeLoader;
+
+#[derive(Debug)]
+pub enum ThemeLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl fmt::Display for ThemeLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeLoaderError::Io(err) => write!(f, "could not read theme: {err}"),
+            ThemeLoaderError::Ron(err) => write!(f, "invalid theme: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeLoaderError {}
+
+impl AssetLoader for ThemeLoader {
+    type Asset = Theme;
+    type Settings = ();
+    type Error = ThemeLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Theme, ThemeLoaderError>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(ThemeLoaderError::Io)?;
+            ron::de::from_bytes(&bytes).map_err(ThemeLoaderError::Ron)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["theme.ron"]
+    }
+}
+
+/// Every theme that can be picked, and the one in use.
+#[derive(Resource)]
+pub struct Themes {
+    handles: Vec<Handle<Theme>>,
+    current: usize,
+}
+
+impl Themes {
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn select(&mut self, index: usize) {
+        if index < self.handles.len() {
+            self.current = index;
+        }
+    }
+}
+
+impl FromWorld for Themes {
+    fn from_world(world: &mut World) -> Self {
+        let current = world
+            .get_resource::<Settings>()
+            .map_or(0, |settings| settings.theme_index());
+        let asset_server = world.resource::<AssetServer>();
+        Self {
+            handles: THEMES
+                .iter()
+                .map(|(_, path)| asset_server.load(*path))
+                .collect(),
+            current,
+        }
+    }
+}
+
+/// A material along with the materials it is swapped for when highlighted.
+pub struct HighlightMaterials {
+    pub base: Handle<StandardMaterial>,
+    hovered: Handle<StandardMaterial>,
+    pressed: Handle<StandardMaterial>,
+    selected: Handle<StandardMaterial>,
+}
+
+impl HighlightMaterials {
+    fn new(materials: &mut Assets<StandardMaterial>) -> Self {
+        Self {
+            base: materials.add(StandardMaterial::default()),
+            hovered: materials.add(StandardMaterial::default()),
+            pressed: materials.add(StandardMaterial::default()),
+            selected: materials.add(StandardMaterial::default()),
+        }
+    }
+
+    pub fn highlight(&self) -> Highlight<StandardMaterial> {
+        Highlight {
+            hovered: Some(HighlightKind::Fixed(self.hovered.clone())),
+            pressed: Some(HighlightKind::Fixed(self.pressed.clone())),
+            selected: Some(HighlightKind::Fixed(self.selected.clone())),
+        }
+    }
+
+    fn update(
+        &self,
+        materials: &mut Assets<StandardMaterial>,
+        base: StandardMaterial,
+        tints: &HighlightTints,
+    ) {
+        for (handle, tint_color) in [
+            (&self.hovered, tints.hovered),
+            (&self.pressed, tints.pressed),
+            (&self.selected, tints.selected),
+        ] {
+            materials.insert(
+                handle.id(),
+                StandardMaterial {
+                    base_color: tint(base.base_color, tint_color),
+                    ..base.clone()
+                },
+            );
+        }
+        materials.insert(self.base.id(), base);
+    }
+}
+
+/// Materials of the current theme, changed in place when another theme is picked.
+#[derive(Resource)]
+pub struct ThemeMaterials {
+    pub light_square: Color,
+    pub dark_square: Color,
+    light_squares: HighlightMaterials,
+    dark_squares: HighlightMaterials,
+    white_pieces: HighlightMaterials,
+    black_pieces: HighlightMaterials,
+}
+
+impl ThemeMaterials {
+    /// Highlight of the squares, light or dark.
+    pub fn square_highlight(&self, light: bool) -> Highlight<StandardMaterial> {
+        if light {
+            self.light_squares.highlight()
+        } else {
+            self.dark_squares.highlight()
+        }
+    }
+
+    pub fn pieces(&self, color: PieceColor) -> &HighlightMaterials {
+        match color {
+            PieceColor::White => &self.white_pieces,
+            PieceColor::Black => &self.black_pieces,
+        }
+    }
+
+    fn apply(
+        &mut self,
+        theme: &Theme,
+        materials: &mut Assets<StandardMaterial>,
+        asset_server: &AssetServer,
+    ) {
+        self.light_square = color(theme.light_square);
+        self.dark_square = color(theme.dark_square);
+        self.light_squares
+            .update(materials, self.light_square.into(), &theme.highlight);
+        self.dark_squares
+            .update(materials, self.dark_square.into(), &theme.highlight);
+        for (pieces, piece_material) in [
+            (&self.white_pieces, &theme.white_pieces),
+            (&self.black_pieces, &theme.black_pieces),
+        ] {
+            let base = StandardMaterial {
+                base_color: color(piece_material.color),
+                base_color_texture: piece_material
+                    .texture
+                    .as_ref()
+                    .map(|path| asset_server.load(path)),
+                metallic: piece_material.metallic,
+                perceptual_roughness: piece_material.roughness,
+                ..Default::default()
+            };
+            pieces.update(materials, base, &theme.highlight);
+        }
+    }
+}
+
+impl FromWorld for ThemeMaterials {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>().clone();
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let mut theme_materials = Self {
+            light_square: Color::WHITE,
+            dark_square: Color::BLACK,
+            light_squares: HighlightMaterials::new(&mut materials),
+            dark_squares: HighlightMaterials::new(&mut materials),
+            white_pieces: HighlightMaterials::new(&mut materials),
+            black_pieces: HighlightMaterials::new(&mut materials),
+        };
+        // Used until the theme asset is loaded
+        theme_materials.apply(&Theme::default(), &mut materials, &asset_server);
+        theme_materials
+    }
+}
+
+/// Apply the picked theme once it is loaded, and again whenever its file changes.
+#[allow(clippy::too_many_arguments)]
+fn apply_theme(
+    mut commands: Commands,
+    mut events: EventReader<AssetEvent<Theme>>,
+    themes: Res<Themes>,
+    theme_assets: Res<Assets<Theme>>,
+    asset_server: Res<AssetServer>,
+    mut theme_materials: ResMut<ThemeMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut ambient_light: ResMut<AmbientLight>,
+    mut lights_query: Query<(&mut PointLight, &mut Transform)>,
+    camera_query: Query<Entity, With<MainCamera>>,
+) {
+    let handle = &themes.handles[themes.current];
+    let theme_changed = events.read().any(|event| match event {
+        AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => {
+            *id == handle.id()
+        }
+        _ => false,
+    });
+    if !theme_changed && !themes.is_changed() {
+        return;
+    }
+    let Some(theme) = theme_assets.get(handle) else {
+        return;
+    };
+
+    theme_materials.apply(theme, &mut materials, &asset_server);
+    ambient_light.brightness = theme.light.ambient;
+    for (mut light, mut transform) in lights_query.iter_mut() {
+        light.color = color(theme.light.color);
+        light.intensity = theme.light.intensity;
+        transform.translation = Vec3::from_array(theme.light.position);
+    }
+    for camera in camera_query.iter() {
+        match &theme.environment_map {
+            Some(environment_map) => commands.entity(camera).insert(EnvironmentMapLight {
+                diffuse_map: asset_server.load(&environment_map.diffuse),
+                specular_map: asset_server.load(&environment_map.specular),
+            }),
+            None => commands.entity(camera).remove::<EnvironmentMapLight>(),
+        };
+    }
+}
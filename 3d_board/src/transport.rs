@@ -0,0 +1,119 @@
+//! How the client reaches the server: TCP, encrypted with TLS when asked, on native platforms,
+//! and a WebSocket in browsers, which can't open TCP connections.
+
+use crate::network::ServerConfig;
+use capnp_rpc::{rpc_twoparty_capnp, twoparty, RpcSystem};
+use fluffy_chess_protocol::fluffy_chess_capnp::handshake;
+use futures::AsyncReadExt;
+use std::future::Future;
+
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    std::fs::File,
+    std::io::{self, BufReader},
+    std::path::Path,
+    std::sync::Arc,
+    tokio_rustls::rustls::{self, pki_types::ServerName},
+    tokio_rustls::TlsConnector,
+    tokio_util::compat::TokioAsyncReadCompatExt,
+};
+
+/// Run the connection with the server next to the game, until the game closes: on a thread of
+/// its own on native platforms, on the event loop of the page in browsers.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn_client<F: Future<Output = ()> + 'static>(client: impl FnOnce() -> F + Send + 'static) {
+    // The future holds RPC clients which can't be sent to another thread, it is made there
+    std::thread::spawn(move || block_on_local(client()));
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn spawn_client<F: Future<Output = ()> + 'static>(client: impl FnOnce() -> F + Send + 'static) {
+    wasm_bindgen_futures::spawn_local(client());
+}
+
+/// Run a future on a runtime of its own, where capnp-rpc can spawn its local tasks.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn block_on_local<T>(future: impl Future<Output = T>) -> T {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("network runtime");
+    tokio::task::LocalSet::new().block_on(&runtime, future)
+}
+
+/// Run a future next to the connection, as a local task of the client.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn_local(future: impl Future<Output = ()> + 'static) {
+    tokio::task::spawn_local(future);
+}
+
+#[cfg(target_arch = "wasm32")]
+pub use wasm_bindgen_futures::spawn_local;
+
+/// Start the RPC system on a connection, returns the capability the server starts with.
+fn bootstrap(stream: impl futures::AsyncRead + futures::AsyncWrite + 'static) -> handshake::Client {
+    let (reader, writer) = stream.split();
+    let network = twoparty::VatNetwork::new(
+        reader,
+        writer,
+        rpc_twoparty_capnp::Side::Client,
+        Default::default(),
+    );
+    let mut rpc_system = RpcSystem::new(Box::new(network), None);
+    let handshake = rpc_system.bootstrap(rpc_twoparty_capnp::Side::Server);
+    spawn_local(async move {
+        let _ = rpc_system.await;
+    });
+    handshake
+}
+
+/// Open a connection to the server, the RPC system runs as a local task.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn open(server: &ServerConfig) -> Result<handshake::Client, Box<dyn std::error::Error>> {
+    let stream = tokio::net::TcpStream::connect(&server.address).await?;
+    stream.set_nodelay(true)?;
+    Ok(if server.tls {
+        let connector = tls_connector(server.trusted_certificate.as_deref())?;
+        let stream = connector
+            .connect(server_name(&server.address)?, stream)
+            .await?;
+        bootstrap(stream.compat())
+    } else {
+        bootstrap(stream.compat())
+    })
+}
+
+/// Open a connection to the WebSocket listener of the server, the RPC system runs as a local
+/// task. The browser decides which certificates to trust.
+#[cfg(target_arch = "wasm32")]
+pub async fn open(server: &ServerConfig) -> Result<handshake::Client, Box<dyn std::error::Error>> {
+    let scheme = if server.tls { "wss" } else { "ws" };
+    let url = format!("{scheme}://{}", server.address);
+    let (_, websocket) = ws_stream_wasm::WsMeta::connect(url, None).await?;
+    Ok(bootstrap(websocket.into_io()))
+}
+
+/// Trust the usual certificate authorities, and the certificates in `trusted_certificate`.
+#[cfg(not(target_arch = "wasm32"))]
+fn tls_connector(trusted_certificate: Option<&Path>) -> io::Result<TlsConnector> {
+    let mut roots = rustls::RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    if let Some(path) = trusted_certificate {
+        for certificate in rustls_pemfile::certs(&mut BufReader::new(File::open(path)?)) {
+            roots.add(certificate?).map_err(io::Error::other)?;
+        }
+    }
+    let config = rustls::ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    Ok(TlsConnector::from(Arc::new(config)))
+}
+
+/// Host of an address, which the certificate of the server must be for.
+#[cfg(not(target_arch = "wasm32"))]
+fn server_name(address: &str) -> Result<ServerName<'static>, Box<dyn std::error::Error>> {
+    let host = address.rsplit_once(':').map_or(address, |(host, _)| host);
+    // IPv6 addresses are between brackets
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    Ok(ServerName::try_from(host.to_string())?)
+}
@@ -1,11 +1,88 @@
-use crate::movement::{PieceColor, PlayerTurn};
+use crate::camera::{CameraSettings, CameraView, MainCamera};
+use crate::chat::{Chat, ChatAuthor};
+use crate::history::MoveHistory;
+use crate::move_input::MoveInput;
+use crate::movement::{Captured, Piece, PieceColor, PlayerTurn};
+use crate::network::{ChallengeAnswer, GameAction, GameResult, OnlineGame, TakebackAnswer};
+use crate::piece_set::{PieceSets, PIECE_SETS};
+use crate::settings::Settings;
+use crate::sound::{SoundPacks, SOUND_PACKS};
+use crate::theme::{Themes, THEMES};
+use crate::undo::UndoRequest;
 use bevy::prelude::*;
+use fluffy_chess_protocol::rules::Ending;
+
+const MOVE_COLOR: Color = Color::rgb(0.8, 0.8, 0.8);
+const VIEWED_MOVE_BACKGROUND: Color = Color::rgba(0.3, 0.3, 0.6, 0.8);
+const BUTTON_BACKGROUND: Color = Color::rgba(0., 0., 0., 0.5);
+const INPUT_ERROR_COLOR: Color = Color::rgb(0.9, 0.4, 0.4);
+/// Antialiasing choices, by name and samples per pixel
+/// Number of chat messages shown, the older ones scroll out
+const CHAT_LINES: usize = 8;
+
+const MSAA_SAMPLES: [(&str, u32); 4] = [("Off", 1), ("2x", 2), ("4x", 4), ("8x", 8)];
 
 pub struct UIPlugin;
 impl Plugin for UIPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, init_next_move_text)
-            .add_systems(Update, next_move_text_update);
+        app.init_resource::<EditedField>()
+            .add_systems(
+                Startup,
+                (
+                    init_next_move_text,
+                    init_move_list,
+                    init_undo_buttons,
+                    init_takeback_prompt,
+                    init_move_input,
+                    init_camera_buttons,
+                    init_board_labels,
+                    init_settings,
+                    init_chat,
+                    init_game_actions,
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    next_move_text_update,
+                    material_text_update,
+                    move_list_update,
+                    move_list_click,
+                    undo_button_click,
+                    takeback_prompt_update,
+                    takeback_prompt_click,
+                    move_input_update,
+                    camera_button_click,
+                    auto_flip_button_click,
+                    auto_flip_button_update,
+                    board_labels_update,
+                    settings_button_click,
+                    theme_button_click,
+                    theme_buttons_update,
+                    piece_set_button_click,
+                    piece_set_buttons_update,
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    msaa_button_click,
+                    msaa_buttons_update,
+                    sound_button_click,
+                    sound_buttons_update,
+                    sound_pack_button_click,
+                    sound_pack_buttons_update,
+                    settings_field_click,
+                    settings_field_typing,
+                    settings_fields_update,
+                    chat_button_click,
+                    chat_update,
+                    game_action_click,
+                    game_actions_update,
+                    challenge_prompt_click,
+                    challenge_prompt_update,
+                ),
+            );
     }
 }
 
@@ -13,6 +90,145 @@ impl Plugin for UIPlugin {
 #[derive(Component)]
 struct NextMoveText;
 
+// Component to mark the Text entity showing the material difference
+#[derive(Component)]
+struct MaterialText;
+
+// Component to mark the node holding the rows of the move list
+#[derive(Component)]
+struct MoveList;
+
+// Button asking to undo or redo a move
+#[derive(Component)]
+struct UndoButton(UndoRequest);
+
+// Component to mark the prompt shown when the opponent asks for a takeback
+#[derive(Component)]
+struct TakebackPrompt;
+
+// Button answering the takeback asked by the opponent
+#[derive(Component)]
+struct TakebackButton {
+    accept: bool,
+}
+
+// Component to mark the Text entity showing the move being typed
+#[derive(Component)]
+struct MoveInputText;
+
+// Component to mark the Text entity showing why a typed move can't be played
+#[derive(Component)]
+struct MoveInputErrorText;
+
+// Button moving the camera to one of the preset views
+#[derive(Component)]
+struct CameraButton(CameraView);
+
+// Button switching between flipping the board after each move and a fixed view
+#[derive(Component)]
+struct AutoFlipButton;
+
+// Name of a file or a rank, shown beside the board
+#[derive(Component)]
+enum BoardLabel {
+    File(u8),
+    Rank(u8),
+}
+
+// Button showing or hiding the settings
+#[derive(Component)]
+struct SettingsButton;
+
+// Component to mark the settings panel
+#[derive(Component)]
+struct SettingsPanel;
+
+// Button picking one of the THEMES
+#[derive(Component)]
+struct ThemeButton(usize);
+
+// Button picking one of the PIECE_SETS
+#[derive(Component)]
+struct PieceSetButton(usize);
+
+// Button picking the number of samples per pixel for antialiasing
+#[derive(Component)]
+struct MsaaButton(u32);
+
+// Button changing the sound effects
+#[derive(Component)]
+enum SoundButton {
+    Mute,
+    /// Change the volume by this much
+    Volume(f32),
+}
+
+// Button picking one of the SOUND_PACKS
+#[derive(Component)]
+struct SoundPackButton(usize);
+
+// Component to mark the Text entity showing the volume
+#[derive(Component)]
+struct VolumeText;
+
+// Settings typed in on the keyboard, edited after clicking on them
+#[derive(Component, Clone, Copy, PartialEq)]
+enum SettingsField {
+    Server,
+    User,
+}
+
+/// Settings field being typed in, if any
+#[derive(Resource, Default)]
+struct EditedField(Option<SettingsField>);
+
+// Component to mark the part of the chat panel hidden when it is collapsed
+#[derive(Component)]
+struct ChatBody;
+
+// Component to mark the Text entity showing the last chat messages
+#[derive(Component)]
+struct ChatLinesText;
+
+// Button of the chat panel
+#[derive(Component, Clone, Copy, PartialEq)]
+enum ChatButton {
+    /// Show or hide the messages
+    Toggle,
+    /// Start typing a message
+    Type,
+    /// Hide or show the messages of the opponent
+    Mute,
+}
+
+// Component to mark the prompt shown when the opponent offers a draw
+#[derive(Component)]
+struct DrawPrompt;
+
+// Button ending an online game, or answering a draw offer
+#[derive(Component)]
+struct GameActionButton(GameAction);
+
+// Component to mark the prompt shown when someone invites the player to a game
+#[derive(Component)]
+struct ChallengePrompt;
+
+// Component to mark the Text entity describing the game the player is invited to
+#[derive(Component)]
+struct ChallengeText;
+
+// Button answering the game the player is invited to
+#[derive(Component)]
+struct ChallengeButton {
+    accept: bool,
+}
+
+// Button of a move in the move list, showing the position after `ply` moves when clicked
+#[derive(Component)]
+struct MoveButton {
+    ply: usize,
+}
+
 /// Initialize UiCamera and text
 fn init_next_move_text(mut commands: Commands, asset_server: ResMut<AssetServer>) {
     let font = asset_server.load("fonts/FiraSans-Bold.ttf");
@@ -24,6 +240,7 @@ fn init_next_move_text(mut commands: Commands, asset_server: ResMut<AssetServer>
                 position_type: PositionType::Absolute,
                 left: Val::Px(10.),
                 top: Val::Px(10.),
+                flex_direction: FlexDirection::Column,
                 ..Default::default()
             },
             ..Default::default()
@@ -34,7 +251,7 @@ fn init_next_move_text(mut commands: Commands, asset_server: ResMut<AssetServer>
                     text: Text::from_section(
                         "Next move: White".to_string(),
                         TextStyle {
-                            font,
+                            font: font.clone(),
                             font_size: 40.0,
                             color: Color::rgb(0.8, 0.8, 0.8),
                             ..Default::default()
@@ -44,12 +261,54 @@ fn init_next_move_text(mut commands: Commands, asset_server: ResMut<AssetServer>
                 },
                 NextMoveText,
             ));
+            parent.spawn((
+                TextBundle::from_section(
+                    "Material: even",
+                    TextStyle {
+                        font,
+                        font_size: 24.0,
+                        color: MOVE_COLOR,
+                    },
+                ),
+                MaterialText,
+            ));
         });
 }
 
+fn color_name(color: PieceColor) -> &'static str {
+    match color {
+        PieceColor::White => "White",
+        PieceColor::Black => "Black",
+    }
+}
+
+fn result_text(result: GameResult) -> String {
+    match result {
+        GameResult::Checkmate { winner } => format!("Checkmate, {} wins", color_name(winner)),
+        GameResult::Stalemate => "Draw by stalemate".to_string(),
+        GameResult::Resignation { winner } => format!(
+            "{} resigned, {} wins",
+            color_name(winner.opposite()),
+            color_name(winner)
+        ),
+        GameResult::Abandoned { winner } => format!(
+            "{} left, {} wins",
+            color_name(winner.opposite()),
+            color_name(winner)
+        ),
+        GameResult::DrawAgreed => "Draw by agreement".to_string(),
+        GameResult::Aborted => "Game aborted".to_string(),
+    }
+}
+
 /// Update text with the correct turn
-fn next_move_text_update(turn: Res<PlayerTurn>, mut query: Query<(&mut Text, &NextMoveText)>) {
-    if !turn.is_changed() {
+fn next_move_text_update(
+    turn: Res<PlayerTurn>,
+    online: Option<Res<OnlineGame>>,
+    mut query: Query<(&mut Text, &NextMoveText)>,
+) {
+    let online_changed = online.as_ref().is_some_and(|online| online.is_changed());
+    if !turn.is_changed() && !online_changed {
         return;
     }
     let Ok((mut text, _tag)) = query.get_single_mut() else {
@@ -58,11 +317,1285 @@ fn next_move_text_update(turn: Res<PlayerTurn>, mut query: Query<(&mut Text, &Ne
     let Some(section) = text.sections.get_mut(0) else {
         return;
     };
-    section.value = format!(
-        "Next move: {}",
-        match turn.color {
-            PieceColor::White => "White",
-            PieceColor::Black => "Black",
+    if let Some(result) = online.as_ref().and_then(|online| online.result) {
+        section.value = result_text(result);
+        return;
+    }
+    if let Some(ending) = turn.ending {
+        section.value = result_text(match ending {
+            Ending::Checkmate => GameResult::Checkmate {
+                winner: turn.color.opposite(),
+            },
+            Ending::Stalemate => GameResult::Stalemate,
+        });
+        return;
+    }
+    let color = color_name(turn.color);
+    section.value = match online.and_then(|online| online.watching) {
+        Some(id) => format!("Watching game {id}, next move: {color}"),
+        None => format!("Next move: {color}"),
+    };
+}
+
+/// Update the material difference between the pieces left on the board
+fn material_text_update(
+    taken_query: Query<(), Changed<Captured>>,
+    mut brought_back: RemovedComponents<Captured>,
+    pieces_query: Query<&Piece, Without<Captured>>,
+    mut query: Query<&mut Text, With<MaterialText>>,
+) {
+    if taken_query.is_empty() && brought_back.read().count() == 0 {
+        return;
+    }
+    let Ok(mut text) = query.get_single_mut() else {
+        return;
+    };
+    let Some(section) = text.sections.get_mut(0) else {
+        return;
+    };
+    let balance: i32 = pieces_query
+        .iter()
+        .map(|piece| match piece.color {
+            PieceColor::White => piece.piece_type.value() as i32,
+            PieceColor::Black => -(piece.piece_type.value() as i32),
+        })
+        .sum();
+    section.value = match balance {
+        0 => "Material: even".to_string(),
+        balance if balance > 0 => format!("Material: White +{balance}"),
+        balance => format!("Material: Black +{}", -balance),
+    };
+}
+
+/// Initialize the side panel listing the moves of the game
+fn init_move_list(mut commands: Commands, asset_server: ResMut<AssetServer>) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(10.),
+                top: Val::Px(10.),
+                bottom: Val::Px(10.),
+                width: Val::Px(200.),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(8.)),
+                ..Default::default()
+            },
+            background_color: Color::rgba(0., 0., 0., 0.5).into(),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Moves",
+                TextStyle {
+                    font,
+                    font_size: 30.0,
+                    color: MOVE_COLOR,
+                },
+            ));
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        // Keep the latest moves in view when the list gets too long
+                        justify_content: JustifyContent::FlexEnd,
+                        flex_grow: 1.,
+                        overflow: Overflow::clip_y(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                MoveList,
+            ));
+        });
+}
+
+/// Rebuild the move list when a move is played or another position is viewed
+fn move_list_update(
+    mut commands: Commands,
+    asset_server: ResMut<AssetServer>,
+    history: Res<MoveHistory>,
+    query: Query<Entity, With<MoveList>>,
+) {
+    if !history.is_changed() {
+        return;
+    }
+    let Ok(move_list) = query.get_single() else {
+        return;
+    };
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let text_style = TextStyle {
+        font,
+        font_size: 20.0,
+        color: MOVE_COLOR,
+    };
+    let viewed_ply = history.viewed_ply();
+
+    commands
+        .entity(move_list)
+        .despawn_descendants()
+        .with_children(|parent| {
+            // Two moves per row, white then black
+            for (row, records) in history.records().chunks(2).enumerate() {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Row,
+                            flex_shrink: 0.,
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn(
+                            TextBundle::from_section(format!("{}.", row + 1), text_style.clone())
+                                .with_style(Style {
+                                    width: Val::Px(40.),
+                                    ..Default::default()
+                                }),
+                        );
+                        for (i, record) in records.iter().enumerate() {
+                            let ply = row * 2 + i + 1;
+                            parent
+                                .spawn((
+                                    ButtonBundle {
+                                        style: Style {
+                                            width: Val::Px(70.),
+                                            padding: UiRect::horizontal(Val::Px(4.)),
+                                            ..Default::default()
+                                        },
+                                        background_color: if ply == viewed_ply {
+                                            VIEWED_MOVE_BACKGROUND.into()
+                                        } else {
+                                            Color::NONE.into()
+                                        },
+                                        ..Default::default()
+                                    },
+                                    MoveButton { ply },
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn(TextBundle::from_section(
+                                        record.san.clone(),
+                                        text_style.clone(),
+                                    ));
+                                });
+                        }
+                    });
+            }
+        });
+}
+
+/// Show the position after the clicked move
+fn move_list_click(
+    mut history: ResMut<MoveHistory>,
+    query: Query<(&Interaction, &MoveButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in query.iter() {
+        if *interaction == Interaction::Pressed {
+            history.view(button.ply);
+        }
+    }
+}
+
+fn spawn_button(parent: &mut ChildBuilder, label: &str, font: Handle<Font>, tag: impl Bundle) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    padding: UiRect::axes(Val::Px(12.), Val::Px(4.)),
+                    margin: UiRect::right(Val::Px(8.)),
+                    ..Default::default()
+                },
+                background_color: BUTTON_BACKGROUND.into(),
+                ..Default::default()
+            },
+            tag,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                label,
+                TextStyle {
+                    font,
+                    font_size: 24.0,
+                    color: MOVE_COLOR,
+                },
+            ));
+        });
+}
+
+/// Initialize the undo and redo buttons
+fn init_undo_buttons(mut commands: Commands, asset_server: ResMut<AssetServer>) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(10.),
+                bottom: Val::Px(10.),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            spawn_button(parent, "Undo", font.clone(), UndoButton(UndoRequest::Undo));
+            spawn_button(parent, "Redo", font, UndoButton(UndoRequest::Redo));
+        });
+}
+
+fn undo_button_click(
+    mut requests: EventWriter<UndoRequest>,
+    query: Query<(&Interaction, &UndoButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in query.iter() {
+        if *interaction == Interaction::Pressed {
+            requests.send(button.0);
+        }
+    }
+}
+
+/// Initialize the prompt answering the opponent's takeback requests, hidden until they ask
+fn init_takeback_prompt(mut commands: Commands, asset_server: ResMut<AssetServer>) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(10.),
+                    top: Val::Px(90.),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(8.)),
+                    ..Default::default()
+                },
+                background_color: BUTTON_BACKGROUND.into(),
+                visibility: Visibility::Hidden,
+                ..Default::default()
+            },
+            TakebackPrompt,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Your opponent asks to take back the last move",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 24.0,
+                    color: MOVE_COLOR,
+                },
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        margin: UiRect::top(Val::Px(8.)),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    spawn_button(
+                        parent,
+                        "Accept",
+                        font.clone(),
+                        TakebackButton { accept: true },
+                    );
+                    spawn_button(parent, "Decline", font, TakebackButton { accept: false });
+                });
+        });
+}
+
+/// Show the prompt while the opponent waits for an answer
+fn takeback_prompt_update(
+    online: Option<Res<OnlineGame>>,
+    mut query: Query<&mut Visibility, With<TakebackPrompt>>,
+) {
+    let Ok(mut visibility) = query.get_single_mut() else {
+        return;
+    };
+    *visibility = if online.is_some_and(|online| online.takeback_requested()) {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+}
+
+fn takeback_prompt_click(
+    mut answers: EventWriter<TakebackAnswer>,
+    query: Query<(&Interaction, &TakebackButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in query.iter() {
+        if *interaction == Interaction::Pressed {
+            answers.send(TakebackAnswer {
+                accept: button.accept,
+            });
         }
-    );
+    }
+}
+
+/// Initialize the field showing the move typed on the keyboard
+fn init_move_input(mut commands: Commands, asset_server: ResMut<AssetServer>) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(10.),
+                bottom: Val::Px(60.),
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 24.0,
+                        color: INPUT_ERROR_COLOR,
+                    },
+                ),
+                MoveInputErrorText,
+            ));
+            parent.spawn((
+                TextBundle::from_section(
+                    "Type a move: ",
+                    TextStyle {
+                        font,
+                        font_size: 24.0,
+                        color: MOVE_COLOR,
+                    },
+                )
+                .with_background_color(BUTTON_BACKGROUND),
+                MoveInputText,
+            ));
+        });
+}
+
+fn move_input_update(
+    input: Res<MoveInput>,
+    mut text_query: Query<&mut Text, (With<MoveInputText>, Without<MoveInputErrorText>)>,
+    mut error_query: Query<&mut Text, (With<MoveInputErrorText>, Without<MoveInputText>)>,
+) {
+    if !input.is_changed() {
+        return;
+    }
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = format!("Type a move: {}", input.text);
+    }
+    if let Ok(mut text) = error_query.get_single_mut() {
+        text.sections[0].value = input.error.clone().unwrap_or_default();
+    }
+}
+
+fn auto_flip_label(settings: &CameraSettings) -> &'static str {
+    if settings.auto_flip {
+        "Flip: on"
+    } else {
+        "Flip: off"
+    }
+}
+
+/// Initialize the buttons choosing the camera view
+fn init_camera_buttons(
+    mut commands: Commands,
+    asset_server: ResMut<AssetServer>,
+    settings: Res<CameraSettings>,
+) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Px(220.),
+                bottom: Val::Px(10.),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            spawn_button(
+                parent,
+                "White",
+                font.clone(),
+                CameraButton(CameraView::White),
+            );
+            spawn_button(
+                parent,
+                "Black",
+                font.clone(),
+                CameraButton(CameraView::Black),
+            );
+            spawn_button(
+                parent,
+                "Top",
+                font.clone(),
+                CameraButton(CameraView::TopDown),
+            );
+            spawn_button(parent, "Side", font.clone(), CameraButton(CameraView::Side));
+            spawn_button(parent, "Settings", font.clone(), SettingsButton);
+            spawn_button(parent, auto_flip_label(&settings), font, AutoFlipButton);
+        });
+}
+
+fn camera_button_click(
+    mut views: EventWriter<CameraView>,
+    query: Query<(&Interaction, &CameraButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in query.iter() {
+        if *interaction == Interaction::Pressed {
+            views.send(button.0);
+        }
+    }
+}
+
+fn auto_flip_button_click(
+    mut settings: ResMut<CameraSettings>,
+    query: Query<&Interaction, (Changed<Interaction>, With<AutoFlipButton>)>,
+) {
+    for interaction in query.iter() {
+        if *interaction == Interaction::Pressed {
+            settings.auto_flip = !settings.auto_flip;
+        }
+    }
+}
+
+fn auto_flip_button_update(
+    settings: Res<CameraSettings>,
+    button_query: Query<&Children, With<AutoFlipButton>>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for children in button_query.iter() {
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.sections[0].value = auto_flip_label(&settings).to_string();
+            }
+        }
+    }
+}
+
+/// Initialize the names of the files and ranks, placed beside the board by board_labels_update
+fn init_board_labels(mut commands: Commands, asset_server: ResMut<AssetServer>) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let text_style = TextStyle {
+        font,
+        font_size: 20.0,
+        color: MOVE_COLOR,
+    };
+
+    for i in 0..8u8 {
+        for (name, label) in [
+            ((b'a' + i) as char, BoardLabel::File(i)),
+            ((b'1' + i) as char, BoardLabel::Rank(i)),
+        ] {
+            commands.spawn((
+                TextBundle::from_section(name.to_string(), text_style.clone()).with_style(Style {
+                    position_type: PositionType::Absolute,
+                    ..Default::default()
+                }),
+                label,
+            ));
+        }
+    }
+}
+
+/// Keep the labels beside the board as the camera turns around it, files on the near side and
+/// ranks on the left.
+fn board_labels_update(
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut query: Query<(&mut Style, &mut Visibility, &Node, &BoardLabel)>,
+) {
+    let Ok((camera, camera_transform)) = camera_query.get_single() else {
+        return;
+    };
+    let files_x = if camera_transform.forward().x >= 0. {
+        -0.75
+    } else {
+        7.75
+    };
+    let ranks_z = if camera_transform.right().z >= 0. {
+        -0.75
+    } else {
+        7.75
+    };
+    for (mut style, mut visibility, node, label) in query.iter_mut() {
+        let position = match label {
+            BoardLabel::File(file) => Vec3::new(files_x, 0., *file as f32),
+            BoardLabel::Rank(rank) => Vec3::new(*rank as f32, 0., ranks_z),
+        };
+        match camera.world_to_viewport(camera_transform, position) {
+            Some(position) => {
+                // Center the label on its position
+                let position = position - node.size() / 2.;
+                style.left = Val::Px(position.x);
+                style.top = Val::Px(position.y);
+                *visibility = Visibility::Inherited;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+/// Spawn a heading and a row of settings below it
+fn spawn_settings_row(
+    parent: &mut ChildBuilder,
+    heading: &str,
+    font: Handle<Font>,
+    spawn_row: impl FnOnce(&mut ChildBuilder),
+) {
+    parent.spawn(TextBundle::from_section(
+        heading,
+        TextStyle {
+            font,
+            font_size: 24.0,
+            color: MOVE_COLOR,
+        },
+    ));
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                margin: UiRect::vertical(Val::Px(8.)),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .with_children(spawn_row);
+}
+
+/// Initialize the settings panel, hidden until the settings button is pressed
+fn init_settings(mut commands: Commands, asset_server: ResMut<AssetServer>) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(220.),
+                    bottom: Val::Px(50.),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(8.)),
+                    ..Default::default()
+                },
+                background_color: BUTTON_BACKGROUND.into(),
+                visibility: Visibility::Hidden,
+                ..Default::default()
+            },
+            SettingsPanel,
+        ))
+        .with_children(|parent| {
+            spawn_settings_row(parent, "Theme", font.clone(), |parent| {
+                for (index, (name, _)) in THEMES.iter().enumerate() {
+                    spawn_button(parent, name, font.clone(), ThemeButton(index));
+                }
+            });
+            spawn_settings_row(parent, "Pieces", font.clone(), |parent| {
+                for (index, (name, _)) in PIECE_SETS.iter().enumerate() {
+                    spawn_button(parent, name, font.clone(), PieceSetButton(index));
+                }
+            });
+            spawn_settings_row(parent, "Antialiasing", font.clone(), |parent| {
+                for (name, samples) in MSAA_SAMPLES {
+                    spawn_button(parent, name, font.clone(), MsaaButton(samples));
+                }
+            });
+            spawn_settings_row(parent, "Sound", font.clone(), |parent| {
+                spawn_button(parent, "", font.clone(), SoundButton::Mute);
+                spawn_button(parent, "-", font.clone(), SoundButton::Volume(-0.1));
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 24.0,
+                            color: MOVE_COLOR,
+                        },
+                    )
+                    .with_style(Style {
+                        margin: UiRect::right(Val::Px(8.)),
+                        ..Default::default()
+                    }),
+                    VolumeText,
+                ));
+                spawn_button(parent, "+", font.clone(), SoundButton::Volume(0.1));
+            });
+            spawn_settings_row(parent, "Sound pack", font.clone(), |parent| {
+                for (index, (name, _)) in SOUND_PACKS.iter().enumerate() {
+                    spawn_button(parent, name, font.clone(), SoundPackButton(index));
+                }
+            });
+            spawn_settings_row(
+                parent,
+                "Online, from the next start",
+                font.clone(),
+                |parent| {
+                    spawn_button(parent, "", font.clone(), SettingsField::Server);
+                    spawn_button(parent, "", font.clone(), SettingsField::User);
+                },
+            );
+        });
+}
+
+fn settings_button_click(
+    button_query: Query<&Interaction, (Changed<Interaction>, With<SettingsButton>)>,
+    mut panel_query: Query<&mut Visibility, With<SettingsPanel>>,
+) {
+    for interaction in button_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        for mut visibility in panel_query.iter_mut() {
+            *visibility = match *visibility {
+                Visibility::Hidden => Visibility::Inherited,
+                _ => Visibility::Hidden,
+            };
+        }
+    }
+}
+
+fn theme_button_click(
+    mut themes: ResMut<Themes>,
+    query: Query<(&Interaction, &ThemeButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in query.iter() {
+        if *interaction == Interaction::Pressed {
+            themes.select(button.0);
+        }
+    }
+}
+
+/// Show which theme is in use
+fn theme_buttons_update(
+    themes: Res<Themes>,
+    mut query: Query<(&mut BackgroundColor, &ThemeButton)>,
+) {
+    if !themes.is_changed() {
+        return;
+    }
+    for (mut background, button) in query.iter_mut() {
+        *background = if button.0 == themes.current() {
+            VIEWED_MOVE_BACKGROUND.into()
+        } else {
+            BUTTON_BACKGROUND.into()
+        };
+    }
+}
+
+fn piece_set_button_click(
+    mut piece_sets: ResMut<PieceSets>,
+    query: Query<(&Interaction, &PieceSetButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in query.iter() {
+        if *interaction == Interaction::Pressed {
+            piece_sets.select(button.0);
+        }
+    }
+}
+
+/// Show which piece set is in use
+fn piece_set_buttons_update(
+    piece_sets: Res<PieceSets>,
+    mut query: Query<(&mut BackgroundColor, &PieceSetButton)>,
+) {
+    if !piece_sets.is_changed() {
+        return;
+    }
+    for (mut background, button) in query.iter_mut() {
+        *background = if button.0 == piece_sets.current() {
+            VIEWED_MOVE_BACKGROUND.into()
+        } else {
+            BUTTON_BACKGROUND.into()
+        };
+    }
+}
+
+fn msaa_button_click(
+    mut settings: ResMut<Settings>,
+    query: Query<(&Interaction, &MsaaButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in query.iter() {
+        if *interaction == Interaction::Pressed {
+            settings.msaa_samples = button.0;
+        }
+    }
+}
+
+/// Show which antialiasing is in use
+fn msaa_buttons_update(
+    settings: Res<Settings>,
+    mut query: Query<(&mut BackgroundColor, &MsaaButton)>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (mut background, button) in query.iter_mut() {
+        *background = if button.0 == settings.msaa_samples {
+            VIEWED_MOVE_BACKGROUND.into()
+        } else {
+            BUTTON_BACKGROUND.into()
+        };
+    }
+}
+
+fn sound_button_click(
+    mut settings: ResMut<Settings>,
+    query: Query<(&Interaction, &SoundButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            SoundButton::Mute => settings.muted = !settings.muted,
+            SoundButton::Volume(change) => {
+                // Rounded, so that steps of a tenth add up to 0 and 1 exactly
+                settings.volume = ((settings.volume + change) * 10.).round().clamp(0., 10.) / 10.;
+            }
+        }
+    }
+}
+
+fn sound_buttons_update(
+    settings: Res<Settings>,
+    button_query: Query<(&Children, &SoundButton)>,
+    mut volume_query: Query<&mut Text, With<VolumeText>>,
+    mut text_query: Query<&mut Text, Without<VolumeText>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (children, button) in button_query.iter() {
+        if !matches!(button, SoundButton::Mute) {
+            continue;
+        }
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.sections[0].value = if settings.muted {
+                    "Muted".to_string()
+                } else {
+                    "Sound on".to_string()
+                };
+            }
+        }
+    }
+    if let Ok(mut text) = volume_query.get_single_mut() {
+        text.sections[0].value = format!("{:.0}%", settings.volume * 100.);
+    }
+}
+
+fn sound_pack_button_click(
+    mut sound_packs: ResMut<SoundPacks>,
+    query: Query<(&Interaction, &SoundPackButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in query.iter() {
+        if *interaction == Interaction::Pressed {
+            sound_packs.select(button.0);
+        }
+    }
+}
+
+/// Show which sound pack is in use
+fn sound_pack_buttons_update(
+    sound_packs: Res<SoundPacks>,
+    mut query: Query<(&mut BackgroundColor, &SoundPackButton)>,
+) {
+    if !sound_packs.is_changed() {
+        return;
+    }
+    for (mut background, button) in query.iter_mut() {
+        *background = if button.0 == sound_packs.current() {
+            VIEWED_MOVE_BACKGROUND.into()
+        } else {
+            BUTTON_BACKGROUND.into()
+        };
+    }
+}
+
+fn settings_field_click(
+    mut edited: ResMut<EditedField>,
+    mut input: ResMut<MoveInput>,
+    query: Query<(&Interaction, &SettingsField), Changed<Interaction>>,
+) {
+    for (interaction, field) in query.iter() {
+        if *interaction == Interaction::Pressed {
+            edited.0 = Some(*field);
+            input.suspended = true;
+        }
+    }
+}
+
+/// Type in the settings field being edited, until Enter or Escape is pressed
+fn settings_field_typing(
+    keys: Res<Input<KeyCode>>,
+    mut characters: EventReader<ReceivedCharacter>,
+    mut edited: ResMut<EditedField>,
+    mut input: ResMut<MoveInput>,
+    mut settings: ResMut<Settings>,
+) {
+    let Some(field) = edited.0 else {
+        characters.clear();
+        return;
+    };
+    let mut text = match field {
+        SettingsField::Server => settings.server.clone().unwrap_or_default(),
+        SettingsField::User => settings.user.clone(),
+    };
+    for character in characters.read() {
+        if !character.char.is_control() {
+            text.push(character.char);
+        }
+    }
+    if keys.just_pressed(KeyCode::Back) {
+        text.pop();
+    }
+    match field {
+        SettingsField::Server => {
+            let server = (!text.is_empty()).then_some(text);
+            if settings.server != server {
+                settings.server = server;
+            }
+        }
+        SettingsField::User => {
+            if settings.user != text {
+                settings.user = text;
+            }
+        }
+    }
+    if keys.any_just_pressed([KeyCode::Return, KeyCode::NumpadEnter, KeyCode::Escape]) {
+        edited.0 = None;
+        input.suspended = false;
+    }
+}
+
+/// Show the typed settings, with a cursor in the one being edited
+fn settings_fields_update(
+    settings: Res<Settings>,
+    edited: Res<EditedField>,
+    mut button_query: Query<(&Children, &SettingsField, &mut BackgroundColor)>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !settings.is_changed() && !edited.is_changed() {
+        return;
+    }
+    for (children, field, mut background) in button_query.iter_mut() {
+        let is_edited = edited.0 == Some(*field);
+        let cursor = if is_edited { "|" } else { "" };
+        let label = match field {
+            SettingsField::Server => format!(
+                "Server: {}{cursor}",
+                settings
+                    .server
+                    .as_deref()
+                    .unwrap_or(if is_edited { "" } else { "none" })
+            ),
+            SettingsField::User => format!("Name: {}{cursor}", settings.user),
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.sections[0].value = label.clone();
+            }
+        }
+        *background = if is_edited {
+            VIEWED_MOVE_BACKGROUND.into()
+        } else {
+            BUTTON_BACKGROUND.into()
+        };
+    }
+}
+
+/// Initialize the chat panel, collapsed, in online games only
+fn init_chat(
+    mut commands: Commands,
+    asset_server: ResMut<AssetServer>,
+    online: Option<Res<OnlineGame>>,
+) {
+    if online.is_none() {
+        return;
+    }
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(10.),
+                top: Val::Px(180.),
+                max_width: Val::Px(360.),
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent.spawn(NodeBundle::default()).with_children(|parent| {
+                spawn_button(parent, "Chat", font.clone(), ChatButton::Toggle);
+                spawn_button(parent, "Mute", font.clone(), ChatButton::Mute);
+            });
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Column,
+                            margin: UiRect::top(Val::Px(8.)),
+                            padding: UiRect::all(Val::Px(8.)),
+                            ..Default::default()
+                        },
+                        background_color: BUTTON_BACKGROUND.into(),
+                        visibility: Visibility::Hidden,
+                        ..Default::default()
+                    },
+                    ChatBody,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((TextBundle::default(), ChatLinesText));
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                margin: UiRect::top(Val::Px(8.)),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        })
+                        .with_children(|parent| {
+                            spawn_button(parent, "Say: ", font, ChatButton::Type);
+                        });
+                });
+        });
+}
+
+fn chat_button_click(
+    mut chat: ResMut<Chat>,
+    mut input: ResMut<MoveInput>,
+    mut settings: ResMut<Settings>,
+    query: Query<(&Interaction, &ChatButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            ChatButton::Toggle => {
+                chat.open = !chat.open;
+                chat.unread = 0;
+            }
+            ChatButton::Type => {
+                chat.typing = true;
+                input.suspended = true;
+            }
+            ChatButton::Mute => {
+                let Some(opponent) = chat.opponent.clone() else {
+                    continue;
+                };
+                if let Some(index) = settings.muted_users.iter().position(|u| *u == opponent) {
+                    settings.muted_users.remove(index);
+                } else {
+                    settings.muted_users.push(opponent);
+                }
+            }
+        }
+    }
+}
+
+/// Show the last messages, the one being typed, and whether the opponent is muted
+fn chat_update(
+    chat: Res<Chat>,
+    settings: Res<Settings>,
+    asset_server: Res<AssetServer>,
+    mut body_query: Query<&mut Visibility, With<ChatBody>>,
+    mut lines_query: Query<&mut Text, With<ChatLinesText>>,
+    mut button_query: Query<(&Children, &ChatButton, &mut BackgroundColor)>,
+    mut text_query: Query<&mut Text, Without<ChatLinesText>>,
+) {
+    if !chat.is_changed() && !settings.is_changed() {
+        return;
+    }
+    if let Ok(mut visibility) = body_query.get_single_mut() {
+        *visibility = if chat.open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+    if let Ok(mut text) = lines_query.get_single_mut() {
+        let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+        text.sections = chat.lines[chat.lines.len().saturating_sub(CHAT_LINES)..]
+            .iter()
+            .map(|line| {
+                let (value, color) = match &line.author {
+                    ChatAuthor::Me => (format!("{}: {}\n", settings.user, line.text), MOVE_COLOR),
+                    ChatAuthor::Opponent(name) => (format!("{name}: {}\n", line.text), MOVE_COLOR),
+                    ChatAuthor::Server => (format!("{}\n", line.text), INPUT_ERROR_COLOR),
+                };
+                TextSection::new(
+                    value,
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 20.0,
+                        color,
+                    },
+                )
+            })
+            .collect();
+    }
+    let muted = chat.opponent_muted(&settings);
+    for (children, button, mut background) in button_query.iter_mut() {
+        let label = match button {
+            ChatButton::Toggle if chat.unread > 0 => format!("Chat ({})", chat.unread),
+            ChatButton::Toggle => "Chat".to_string(),
+            ChatButton::Type => {
+                let cursor = if chat.typing { "|" } else { "" };
+                format!("Say: {}{cursor}", chat.draft)
+            }
+            ChatButton::Mute if muted => "Unmute".to_string(),
+            ChatButton::Mute => "Mute".to_string(),
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.sections[0].value = label.clone();
+            }
+        }
+        *background = if (*button == ChatButton::Type && chat.typing)
+            || (*button == ChatButton::Mute && muted)
+        {
+            VIEWED_MOVE_BACKGROUND.into()
+        } else {
+            BUTTON_BACKGROUND.into()
+        };
+    }
+}
+
+/// Initialize the buttons ending the game and the prompts answering draw offers and challenges,
+/// for players of online games
+fn init_game_actions(
+    mut commands: Commands,
+    asset_server: ResMut<AssetServer>,
+    online: Option<Res<OnlineGame>>,
+) {
+    if online.is_none() || online.is_some_and(|online| online.watching.is_some()) {
+        return;
+    }
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(10.),
+                bottom: Val::Px(140.),
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            display: Display::None,
+                            flex_direction: FlexDirection::Column,
+                            margin: UiRect::bottom(Val::Px(8.)),
+                            padding: UiRect::all(Val::Px(8.)),
+                            ..Default::default()
+                        },
+                        background_color: BUTTON_BACKGROUND.into(),
+                        ..Default::default()
+                    },
+                    ChallengePrompt,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 24.0,
+                                color: MOVE_COLOR,
+                            },
+                        ),
+                        ChallengeText,
+                    ));
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                margin: UiRect::top(Val::Px(8.)),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        })
+                        .with_children(|parent| {
+                            spawn_button(
+                                parent,
+                                "Accept",
+                                font.clone(),
+                                ChallengeButton { accept: true },
+                            );
+                            spawn_button(
+                                parent,
+                                "Decline",
+                                font.clone(),
+                                ChallengeButton { accept: false },
+                            );
+                        });
+                });
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            display: Display::None,
+                            flex_direction: FlexDirection::Column,
+                            margin: UiRect::bottom(Val::Px(8.)),
+                            padding: UiRect::all(Val::Px(8.)),
+                            ..Default::default()
+                        },
+                        background_color: BUTTON_BACKGROUND.into(),
+                        ..Default::default()
+                    },
+                    DrawPrompt,
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        "Your opponent offers a draw",
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 24.0,
+                            color: MOVE_COLOR,
+                        },
+                    ));
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                margin: UiRect::top(Val::Px(8.)),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        })
+                        .with_children(|parent| {
+                            spawn_button(
+                                parent,
+                                "Accept",
+                                font.clone(),
+                                GameActionButton(GameAction::AcceptDraw),
+                            );
+                            spawn_button(
+                                parent,
+                                "Decline",
+                                font.clone(),
+                                GameActionButton(GameAction::DeclineDraw),
+                            );
+                        });
+                });
+            parent.spawn(NodeBundle::default()).with_children(|parent| {
+                spawn_button(
+                    parent,
+                    "Offer draw",
+                    font.clone(),
+                    GameActionButton(GameAction::OfferDraw),
+                );
+                spawn_button(
+                    parent,
+                    "Resign",
+                    font.clone(),
+                    GameActionButton(GameAction::Resign),
+                );
+                spawn_button(parent, "Abort", font, GameActionButton(GameAction::Abort));
+            });
+        });
+}
+
+fn game_action_click(
+    mut actions: EventWriter<GameAction>,
+    query: Query<(&Interaction, &GameActionButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in query.iter() {
+        if *interaction == Interaction::Pressed {
+            actions.send(button.0);
+        }
+    }
+}
+
+/// Show the buttons that can be used at this point of the game, and the draw offered by the
+/// opponent
+fn game_actions_update(
+    online: Option<Res<OnlineGame>>,
+    history: Res<MoveHistory>,
+    mut prompt_query: Query<&mut Style, With<DrawPrompt>>,
+    mut button_query: Query<(&Children, &GameActionButton, &mut Style), Without<DrawPrompt>>,
+    mut text_query: Query<&mut Text>,
+) {
+    let Some(online) = online else {
+        return;
+    };
+    if !online.is_changed() && !history.is_changed() {
+        return;
+    }
+    let display = |shown: bool| if shown { Display::Flex } else { Display::None };
+    if let Ok(mut style) = prompt_query.get_single_mut() {
+        style.display = display(online.draw_offered());
+    }
+    let playing = online.result.is_none();
+    // Until both players have moved, the game can be aborted instead of resigned
+    let abortable = history.records().len() < 2;
+    for (children, button, mut style) in button_query.iter_mut() {
+        style.display = display(match button.0 {
+            GameAction::OfferDraw => playing && !abortable,
+            GameAction::Resign => playing && !abortable,
+            GameAction::Abort => playing && abortable,
+            GameAction::AcceptDraw | GameAction::DeclineDraw => true,
+        });
+        if button.0 == GameAction::OfferDraw {
+            let label = if online.draw_asked() {
+                "Draw offered"
+            } else {
+                "Offer draw"
+            };
+            for child in children.iter() {
+                if let Ok(mut text) = text_query.get_mut(*child) {
+                    text.sections[0].value = label.to_string();
+                }
+            }
+        }
+    }
+}
+
+fn challenge_prompt_click(
+    mut answers: EventWriter<ChallengeAnswer>,
+    query: Query<(&Interaction, &ChallengeButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in query.iter() {
+        if *interaction == Interaction::Pressed {
+            answers.send(ChallengeAnswer {
+                accept: button.accept,
+            });
+        }
+    }
+}
+
+/// Show the game the player is invited to while the challenger waits for an answer
+fn challenge_prompt_update(
+    online: Option<Res<OnlineGame>>,
+    mut prompt_query: Query<&mut Style, With<ChallengePrompt>>,
+    mut text_query: Query<&mut Text, With<ChallengeText>>,
+) {
+    let Some(online) = online.filter(|online| online.is_changed()) else {
+        return;
+    };
+    if let Ok(mut style) = prompt_query.get_single_mut() {
+        style.display = if online.challenge().is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+    if let (Some(challenge), Ok(mut text)) = (online.challenge(), text_query.get_single_mut()) {
+        text.sections[0].value = challenge.to_string();
+    }
 }
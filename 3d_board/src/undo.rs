@@ -0,0 +1,170 @@
+use crate::history::MoveHistory;
+use crate::movement::{AttemptMove, Captured, CastlingRights, Move, Piece, PlayerTurn, Square};
+use crate::network::OnlineGame;
+use bevy::prelude::*;
+
+pub struct UndoPlugin;
+impl Plugin for UndoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<UndoRequest>()
+            .add_event::<UndoCommand>()
+            .init_resource::<UndoStack>()
+            .add_systems(
+                Update,
+                (
+                    undo_keys,
+                    forward_undo_requests.run_if(not(resource_exists::<OnlineGame>())),
+                    undo_moves,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Undo or redo asked by the local player.
+///
+/// Local games act on it right away, online games first ask the opponent.
+#[derive(Event, Clone, Copy, PartialEq)]
+pub enum UndoRequest {
+    Undo,
+    Redo,
+}
+
+/// Undo or redo to apply to the board.
+#[derive(Event, Clone, Copy, PartialEq)]
+pub enum UndoCommand {
+    Undo,
+    Redo,
+}
+
+/// A move as it was played, with everything needed to take it back.
+#[derive(Clone, Copy)]
+pub struct PlayedMove {
+    pub piece: Entity,
+    pub done_move: Move,
+    pub captured: Option<(Entity, Piece)>,
+    /// Castling rights before the move
+    pub castling: CastlingRights,
+    /// En passant square before the move
+    pub en_passant: Option<Square>,
+}
+
+#[derive(Resource, Default)]
+pub struct UndoStack {
+    done: Vec<PlayedMove>,
+    undone: Vec<PlayedMove>,
+}
+
+impl UndoStack {
+    pub fn push(&mut self, played: PlayedMove) {
+        // Playing the next undone move again keeps the rest of them available to redo
+        match self.undone.last() {
+            Some(next)
+                if next.piece == played.piece
+                    && next.done_move.square.x == played.done_move.square.x
+                    && next.done_move.square.y == played.done_move.square.y =>
+            {
+                self.undone.pop();
+            }
+            _ => self.undone.clear(),
+        }
+        self.done.push(played);
+    }
+}
+
+fn undo_keys(keys: Res<Input<KeyCode>>, mut requests: EventWriter<UndoRequest>) {
+    if !(keys.pressed(KeyCode::ControlLeft) || keys.pressed(KeyCode::ControlRight)) {
+        return;
+    }
+    let shift = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    if keys.just_pressed(KeyCode::Y) || (shift && keys.just_pressed(KeyCode::Z)) {
+        requests.send(UndoRequest::Redo);
+    } else if keys.just_pressed(KeyCode::Z) {
+        requests.send(UndoRequest::Undo);
+    }
+}
+
+/// Nobody needs to agree on taking back a move in a local game.
+fn forward_undo_requests(
+    mut requests: EventReader<UndoRequest>,
+    mut commands: EventWriter<UndoCommand>,
+) {
+    for request in requests.read() {
+        commands.send(match request {
+            UndoRequest::Undo => UndoCommand::Undo,
+            UndoRequest::Redo => UndoCommand::Redo,
+        });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn undo_moves(
+    mut commands: Commands,
+    mut undo_commands: EventReader<UndoCommand>,
+    mut stack: ResMut<UndoStack>,
+    mut turn: ResMut<PlayerTurn>,
+    mut history: ResMut<MoveHistory>,
+    mut attempt_moves: EventWriter<AttemptMove>,
+    mut pieces_query: Query<(Entity, &mut Piece), Without<Captured>>,
+    squares_query: Query<(Entity, &Square)>,
+) {
+    for command in undo_commands.read() {
+        match command {
+            UndoCommand::Undo => {
+                let Some(played) = stack.done.pop() else {
+                    continue;
+                };
+                let done_move = played.done_move;
+
+                // Put the piece back as it was, it is animated to its square
+                if let Ok((_, mut piece)) = pieces_query.get_mut(played.piece) {
+                    *piece = done_move.piece;
+                }
+                if let Some((rook_from, rook_to)) = done_move.castling_rook() {
+                    if let Some((_, mut rook)) = pieces_query
+                        .iter_mut()
+                        .find(|(_, rook)| rook.x == rook_to.0 && rook.y == rook_to.1)
+                    {
+                        rook.x = rook_from.0;
+                        rook.y = rook_from.1;
+                    }
+                }
+                // Bring the taken piece back from its tray
+                if let Some((captured, _)) = played.captured {
+                    commands.entity(captured).remove::<Captured>();
+                }
+
+                turn.color = done_move.piece.color;
+                turn.castling = played.castling;
+                turn.en_passant = played.en_passant;
+                turn.ending = None;
+                history.take_back();
+                stack.undone.push(played);
+            }
+            UndoCommand::Redo => {
+                let Some(played) = stack.undone.last() else {
+                    continue;
+                };
+                // The move is played on the live board
+                let last_ply = history.records().len();
+                history.view(last_ply);
+                let Some(square) = squares_query.iter().find_map(|(entity, square)| {
+                    if square.x == played.done_move.square.x
+                        && square.y == played.done_move.square.y
+                    {
+                        Some(entity)
+                    } else {
+                        None
+                    }
+                }) else {
+                    continue;
+                };
+                attempt_moves.send(AttemptMove {
+                    piece: played.piece,
+                    square,
+                    promotion: played.done_move.promotion,
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Protocol spoken between the game and the server, generated from `schema/fluffy_chess.capnp`.
+
+use fluffy_chess_capnp::square;
+
+pub mod fluffy_chess_capnp {
+    include!(concat!(env!("OUT_DIR"), "/fluffy_chess_capnp.rs"));
+}
+pub mod rules;
+#[cfg(feature = "websocket")]
+pub mod websocket;
+
+/// A value read from a message of the protocol.
+pub trait FromReader<'a>: Sized {
+    type Reader;
+
+    fn from_reader(reader: Self::Reader) -> capnp::Result<Self>;
+}
+
+/// A value written into a message of the protocol.
+pub trait ToBuilder<'a> {
+    type Builder;
+
+    fn to_builder(&self, builder: Self::Builder);
+}
+
+/// A square as its coordinates.
+impl<'a> FromReader<'a> for (u8, u8) {
+    type Reader = square::Reader<'a>;
+
+    fn from_reader(reader: square::Reader<'a>) -> capnp::Result<Self> {
+        Ok((reader.get_x(), reader.get_y()))
+    }
+}
+
+impl<'a> ToBuilder<'a> for (u8, u8) {
+    type Builder = square::Builder<'a>;
+
+    fn to_builder(&self, mut builder: square::Builder<'a>) {
+        builder.set_x(self.0);
+        builder.set_y(self.1);
+    }
+}
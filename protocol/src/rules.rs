@@ -0,0 +1,558 @@
+//! Rules of chess, shared by the game and the server so that both agree on which moves are legal
+//! and how a game ends.
+//!
+//! Squares are `(rank, file)` pairs from 0 to 7, white starting on rank 0.
+
+use crate::fluffy_chess_capnp::{piece, Color};
+use std::collections::HashMap;
+
+/// Pieces of the back ranks, from the first file to the last
+const BACK_RANK: [piece::Type; 8] = [
+    piece::Type::Rook,
+    piece::Type::Knight,
+    piece::Type::Bishop,
+    piece::Type::Queen,
+    piece::Type::King,
+    piece::Type::Bishop,
+    piece::Type::Knight,
+    piece::Type::Rook,
+];
+
+/// File of the king before castling, and of the rook on the king side and on the queen side
+const KING_FILE: u8 = 4;
+const ROOK_FILES: [u8; 2] = [7, 0];
+
+fn opposite(color: Color) -> Color {
+    match color {
+        Color::White => Color::Black,
+        Color::Black => Color::White,
+    }
+}
+
+/// Rank the pieces of `color` start on.
+fn back_rank(color: Color) -> u8 {
+    match color {
+        Color::White => 0,
+        Color::Black => 7,
+    }
+}
+
+/// Rank reached by the pawns of `color` when they are promoted.
+pub fn last_rank(color: Color) -> u8 {
+    back_rank(opposite(color))
+}
+
+/// Direction the pawns of `color` move in along the ranks.
+fn forward(color: Color) -> i8 {
+    match color {
+        Color::White => 1,
+        Color::Black => -1,
+    }
+}
+
+/// How a game ends when the side to move has no legal move.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Ending {
+    /// The king is in check, the other side wins
+    Checkmate,
+    /// The king is not in check, the game is drawn
+    Stalemate,
+}
+
+/// Position of the pieces of a game, by square, with what the earlier moves allow.
+#[derive(Clone)]
+pub struct Board {
+    pieces: HashMap<(u8, u8), (Color, piece::Type)>,
+    /// Whether each side, by color, can still castle on the king side and on the queen side
+    castling: [[bool; 2]; 2],
+    /// Square skipped by a pawn moving two squares on the last move, where it can be taken
+    en_passant: Option<(u8, u8)>,
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        let mut pieces = HashMap::new();
+        for (y, piece_type) in BACK_RANK.into_iter().enumerate() {
+            let y = y as u8;
+            pieces.insert((0, y), (Color::White, piece_type));
+            pieces.insert((1, y), (Color::White, piece::Type::Pawn));
+            pieces.insert((6, y), (Color::Black, piece::Type::Pawn));
+            pieces.insert((7, y), (Color::Black, piece_type));
+        }
+        Self {
+            pieces,
+            castling: [[true; 2]; 2],
+            en_passant: None,
+        }
+    }
+}
+
+impl Board {
+    /// A position of these pieces, where no side can castle nor take en passant.
+    pub fn with_pieces(pieces: impl IntoIterator<Item = ((u8, u8), (Color, piece::Type))>) -> Self {
+        Self {
+            pieces: pieces.into_iter().collect(),
+            castling: [[false; 2]; 2],
+            en_passant: None,
+        }
+    }
+
+    /// Let `color` castle on one side, as long as its king and rook are on their first squares.
+    pub fn allow_castling(&mut self, color: Color, king_side: bool) {
+        self.castling[color as usize][usize::from(!king_side)] = true;
+    }
+
+    /// Let the pawn that just moved two squares be taken on the square it skipped.
+    pub fn allow_en_passant(&mut self, square: (u8, u8)) {
+        self.en_passant = Some(square);
+    }
+
+    pub fn get(&self, square: (u8, u8)) -> Option<(Color, piece::Type)> {
+        self.pieces.get(&square).copied()
+    }
+
+    /// The pieces on the board, with their squares.
+    pub fn pieces(&self) -> impl Iterator<Item = ((u8, u8), (Color, piece::Type))> + '_ {
+        self.pieces.iter().map(|(&square, &piece)| (square, piece))
+    }
+
+    /// Play a move, taking en passant and moving the rook when castling. A pawn reaching the last
+    /// rank becomes `promotion`.
+    pub fn apply(&mut self, from: (u8, u8), to: (u8, u8), promotion: Option<piece::Type>) {
+        let Some((color, piece_type)) = self.get(from) else {
+            return;
+        };
+        if piece_type == piece::Type::Pawn && self.en_passant == Some(to) && self.get(to).is_none()
+        {
+            self.pieces.remove(&(from.0, to.1));
+        }
+        if self.is_castling(from, to) {
+            let (rook_from, rook_to) = if to.1 > from.1 { (7, 5) } else { (0, 3) };
+            if let Some(rook) = self.pieces.remove(&(from.0, rook_from)) {
+                self.pieces.insert((from.0, rook_to), rook);
+            }
+        }
+        // A side can't castle with a king or a rook that moved or was taken
+        for color in [Color::White, Color::Black] {
+            let rank = back_rank(color);
+            for (side, rook_file) in ROOK_FILES.into_iter().enumerate() {
+                if [from, to].contains(&(rank, KING_FILE))
+                    || [from, to].contains(&(rank, rook_file))
+                {
+                    self.castling[color as usize][side] = false;
+                }
+            }
+        }
+        self.en_passant = (piece_type == piece::Type::Pawn && from.0.abs_diff(to.0) == 2)
+            .then_some(((from.0 + to.0) / 2, from.1));
+        self.pieces.remove(&from);
+        self.pieces
+            .insert(to, (color, promotion.unwrap_or(piece_type)));
+    }
+
+    /// Whether the piece on `from` can move to `to`, without leaving its king in check.
+    pub fn is_legal(&self, from: (u8, u8), to: (u8, u8)) -> bool {
+        self.can_reach(from, to) && !self.leaves_king_in_check(from, to)
+    }
+
+    /// Whether the move is the king castling, by moving from its first square to two files away.
+    pub fn is_castling(&self, from: (u8, u8), to: (u8, u8)) -> bool {
+        self.get(from).is_some_and(|(color, piece_type)| {
+            piece_type == piece::Type::King
+                && from == (back_rank(color), KING_FILE)
+                && from.0 == to.0
+                && from.1.abs_diff(to.1) == 2
+        })
+    }
+
+    /// Whether the piece on `from` can move to `to`, castling included, regardless of the safety
+    /// of its king.
+    pub fn can_reach(&self, from: (u8, u8), to: (u8, u8)) -> bool {
+        let Some((color, piece_type)) = self.get(from) else {
+            return false;
+        };
+        if [from.0, from.1, to.0, to.1]
+            .iter()
+            .any(|&coordinate| coordinate >= 8)
+            || from == to
+            || self
+                .get(to)
+                .is_some_and(|(piece_color, _)| piece_color == color)
+        {
+            return false;
+        }
+        if self.is_castling(from, to) {
+            self.can_castle(color, from, to)
+        } else {
+            self.can_move(from, to, color, piece_type)
+        }
+    }
+
+    /// Whether the side of the piece on `from` is in check after it moves to `to`.
+    pub fn leaves_king_in_check(&self, from: (u8, u8), to: (u8, u8)) -> bool {
+        let Some((color, _)) = self.get(from) else {
+            return false;
+        };
+        let mut after = self.clone();
+        // The piece a pawn becomes doesn't change whether the king is safe
+        after.apply(from, to, None);
+        after.in_check(color)
+    }
+
+    pub fn in_check(&self, color: Color) -> bool {
+        self.pieces
+            .iter()
+            .find(|(_, piece)| **piece == (color, piece::Type::King))
+            .is_some_and(|(&king, _)| self.is_attacked(king, opposite(color)))
+    }
+
+    /// How the game ends if `color`, whose turn it is, has no legal move.
+    pub fn ending(&self, color: Color) -> Option<Ending> {
+        if self.has_legal_move(color) {
+            None
+        } else if self.in_check(color) {
+            Some(Ending::Checkmate)
+        } else {
+            Some(Ending::Stalemate)
+        }
+    }
+
+    fn can_castle(&self, color: Color, from: (u8, u8), to: (u8, u8)) -> bool {
+        let rank = back_rank(color);
+        let side = if to.1 > from.1 { 0 } else { 1 };
+        let rook_file = ROOK_FILES[side];
+        let between = if rook_file > KING_FILE {
+            KING_FILE + 1..rook_file
+        } else {
+            rook_file + 1..KING_FILE
+        };
+        let passed = (rank, (from.1 + to.1) / 2);
+        self.castling[color as usize][side]
+            && self.get((rank, rook_file)) == Some((color, piece::Type::Rook))
+            && between.into_iter().all(|file| self.get((rank, file)).is_none())
+            // The king can't castle out of, through or into check
+            && [from, passed, to]
+                .into_iter()
+                .all(|square| !self.is_attacked(square, opposite(color)))
+    }
+
+    /// Whether the path between two squares on a line, a file or a diagonal is empty.
+    fn is_path_empty(&self, from: (u8, u8), to: (u8, u8)) -> bool {
+        let step = (
+            (to.0 as i8 - from.0 as i8).signum(),
+            (to.1 as i8 - from.1 as i8).signum(),
+        );
+        let mut square = from;
+        loop {
+            square = (
+                (square.0 as i8 + step.0) as u8,
+                (square.1 as i8 + step.1) as u8,
+            );
+            if square == to {
+                return true;
+            }
+            if self.get(square).is_some() {
+                return false;
+            }
+        }
+    }
+
+    /// Whether a piece on `from` attacks `to`, whether or not something stands there.
+    fn attacks(&self, from: (u8, u8), to: (u8, u8), color: Color, piece_type: piece::Type) -> bool {
+        let ranks = to.0 as i8 - from.0 as i8;
+        let files = to.1 as i8 - from.1 as i8;
+        let straight = (ranks == 0) != (files == 0);
+        let diagonal = ranks != 0 && ranks.abs() == files.abs();
+        match piece_type {
+            piece::Type::King => ranks.abs() <= 1 && files.abs() <= 1 && (ranks, files) != (0, 0),
+            piece::Type::Queen => (straight || diagonal) && self.is_path_empty(from, to),
+            piece::Type::Rook => straight && self.is_path_empty(from, to),
+            piece::Type::Bishop => diagonal && self.is_path_empty(from, to),
+            piece::Type::Knight => ranks.abs() * files.abs() == 2,
+            piece::Type::Pawn => ranks == forward(color) && files.abs() == 1,
+        }
+    }
+
+    /// Whether a piece can move from `from` to `to`, other than by castling.
+    fn can_move(
+        &self,
+        from: (u8, u8),
+        to: (u8, u8),
+        color: Color,
+        piece_type: piece::Type,
+    ) -> bool {
+        if piece_type != piece::Type::Pawn {
+            return self.attacks(from, to, color, piece_type);
+        }
+        let ranks = to.0 as i8 - from.0 as i8;
+        if from.1 != to.1 {
+            // Pawns take diagonally, also the pawn that just passed the square
+            return self.attacks(from, to, color, piece_type)
+                && (self.get(to).is_some() || self.en_passant == Some(to));
+        }
+        let start_rank = back_rank(color) as i8 + forward(color);
+        self.get(to).is_none()
+            && (ranks == forward(color)
+                || (ranks == 2 * forward(color)
+                    && from.0 as i8 == start_rank
+                    && self.is_path_empty(from, to)))
+    }
+
+    /// Whether a piece of `by` attacks a square.
+    fn is_attacked(&self, square: (u8, u8), by: Color) -> bool {
+        self.pieces.iter().any(|(&from, &(color, piece_type))| {
+            color == by && self.attacks(from, square, color, piece_type)
+        })
+    }
+
+    fn has_legal_move(&self, color: Color) -> bool {
+        let squares = || (0..8).flat_map(|x| (0..8).map(move |y| (x, y)));
+        self.pieces
+            .iter()
+            .filter(|(_, (piece_color, _))| *piece_color == color)
+            .any(|(&from, _)| squares().any(|to| self.is_legal(from, to)))
+    }
+}
+
+/// The square of a name in algebraic notation, e.g. `e4`. Panics on anything else, it is meant to
+/// write positions down in tests.
+pub fn square(name: &str) -> (u8, u8) {
+    match name.as_bytes() {
+        &[file @ b'a'..=b'h', rank @ b'1'..=b'8'] => (rank - b'1', file - b'a'),
+        _ => panic!("{name} is not a square"),
+    }
+}
+
+/// A position of pieces standing on the named squares, as in `("e1", Color::White,
+/// piece::Type::King)`, where no side can castle nor take en passant.
+pub fn position(pieces: &[(&str, Color, piece::Type)]) -> Board {
+    Board::with_pieces(
+        pieces
+            .iter()
+            .map(|&(name, color, piece_type)| (square(name), (color, piece_type))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use piece::Type::{Bishop, King, Knight, Pawn, Queen, Rook};
+    use Color::{Black, White};
+
+    /// Play moves from the start, panicking on an illegal one.
+    fn play(moves: &[(&str, &str)]) -> Board {
+        let mut board = Board::default();
+        for (from, to) in moves {
+            assert!(board.is_legal(square(from), square(to)), "{from}{to}");
+            board.apply(square(from), square(to), None);
+        }
+        board
+    }
+
+    fn assert_illegal(board: &Board, moves: &[(&str, &str)]) {
+        for (from, to) in moves {
+            assert!(
+                !board.is_legal(square(from), square(to)),
+                "{from}{to} should be illegal"
+            );
+        }
+    }
+
+    #[test]
+    fn legal_moves() {
+        let board = play(&[
+            ("e2", "e4"),
+            ("e7", "e5"),
+            ("g1", "f3"),
+            ("b8", "c6"),
+            ("f1", "c4"),
+            ("g8", "f6"),
+            ("e1", "g1"),
+            ("d7", "d6"),
+            ("d2", "d4"),
+            ("e5", "d4"),
+            ("f3", "d4"),
+            ("c8", "g4"),
+            ("d1", "g4"),
+        ]);
+        assert_eq!(board.get(square("g1")), Some((White, King)));
+        assert_eq!(board.get(square("f1")), Some((White, Rook)));
+        assert_eq!(board.get(square("h1")), None);
+        assert_eq!(board.get(square("d4")), Some((White, Knight)));
+        assert_eq!(board.get(square("g4")), Some((White, Queen)));
+    }
+
+    #[test]
+    fn pieces_move_by_their_rules() {
+        assert_illegal(
+            &Board::default(),
+            &[
+                ("e2", "e5"),
+                ("e2", "d3"),
+                ("e2", "f2"),
+                ("a1", "a3"),
+                ("c1", "e3"),
+                ("d1", "h5"),
+                ("g1", "g3"),
+                ("e1", "e2"),
+                ("e1", "g1"),
+                // The king can't be taken on the first move, or ever
+                ("d1", "e8"),
+            ],
+        );
+
+        // Pawns don't take straight ahead, nor jump over a piece
+        let board = play(&[("e2", "e4"), ("e7", "e5"), ("g1", "e2")]);
+        assert_illegal(&board, &[("e5", "e4"), ("e2", "e3"), ("e4", "e5")]);
+        let board = play(&[("g1", "f3"), ("f7", "f6")]);
+        assert_illegal(&board, &[("f2", "f4")]);
+    }
+
+    #[test]
+    fn en_passant() {
+        let mut board = play(&[("e2", "e4"), ("a7", "a6"), ("e4", "e5"), ("d7", "d5")]);
+        assert!(board.is_legal(square("e5"), square("d6")));
+        board.apply(square("e5"), square("d6"), None);
+        assert_eq!(board.get(square("d5")), None);
+        assert_eq!(board.get(square("d6")), Some((White, Pawn)));
+
+        // Only right after the pawn moved two squares
+        let board = play(&[("e2", "e4"), ("d7", "d5"), ("e4", "e5"), ("a7", "a6")]);
+        assert_illegal(&board, &[("e5", "d6")]);
+        // Not for a pawn that moved one square at a time
+        let board = play(&[
+            ("e2", "e4"),
+            ("d7", "d6"),
+            ("e4", "e5"),
+            ("a7", "a6"),
+            ("h2", "h3"),
+            ("d6", "d5"),
+        ]);
+        assert_illegal(&board, &[("e5", "d6")]);
+    }
+
+    #[test]
+    fn castling() {
+        let mut board = position(&[
+            ("e1", White, King),
+            ("a1", White, Rook),
+            ("h1", White, Rook),
+            ("a8", Black, King),
+        ]);
+        board.allow_castling(White, false);
+        assert_illegal(&board, &[("e1", "g1")]);
+        assert!(board.is_legal(square("e1"), square("c1")));
+        board.apply(square("e1"), square("c1"), None);
+        assert_eq!(board.get(square("d1")), Some((White, Rook)));
+        assert_eq!(board.get(square("a1")), None);
+
+        // Not through, into or out of check
+        for attacker in ["f8", "g8", "e8"] {
+            let mut board = position(&[
+                ("e1", White, King),
+                ("h1", White, Rook),
+                ("a8", Black, King),
+                (attacker, Black, Rook),
+            ]);
+            board.allow_castling(White, true);
+            assert!(!board.is_legal(square("e1"), square("g1")), "{attacker}");
+        }
+
+        // The rights go with a moved king or rook, and with a taken rook
+        let board = play(&[
+            ("e2", "e4"),
+            ("e7", "e5"),
+            ("g1", "f3"),
+            ("g8", "f6"),
+            ("f1", "c4"),
+            ("f8", "c5"),
+            ("e1", "e2"),
+            ("e8", "e7"),
+            ("e2", "e1"),
+            ("e7", "e8"),
+        ]);
+        assert_illegal(&board, &[("e1", "g1")]);
+        let board = play(&[
+            ("g1", "f3"),
+            ("g8", "f6"),
+            ("g2", "g3"),
+            ("g7", "g6"),
+            ("f1", "g2"),
+            ("f8", "g7"),
+            ("h1", "g1"),
+            ("h8", "g8"),
+            ("g1", "h1"),
+            ("g8", "h8"),
+        ]);
+        assert_illegal(&board, &[("e1", "g1")]);
+        let mut board = position(&[
+            ("e1", White, King),
+            ("h1", White, Rook),
+            ("a8", Black, King),
+            ("h8", Black, Rook),
+        ]);
+        board.allow_castling(White, true);
+        board.apply(square("h8"), square("h1"), None);
+        assert!(!board.castling[White as usize][0]);
+    }
+
+    #[test]
+    fn king_stays_out_of_check() {
+        // The bishop is pinned
+        let board = position(&[
+            ("e1", White, King),
+            ("e2", White, Bishop),
+            ("a8", Black, King),
+            ("e8", Black, Rook),
+        ]);
+        assert_illegal(&board, &[("e2", "d3")]);
+        assert!(board.is_legal(square("e1"), square("d1")));
+
+        let board = position(&[
+            ("e1", White, King),
+            ("a8", Black, King),
+            ("d8", Black, Rook),
+        ]);
+        assert_illegal(&board, &[("e1", "d1"), ("e1", "d2")]);
+        assert!(board.is_legal(square("e1"), square("f1")));
+
+        // A check is answered
+        let board = position(&[
+            ("e1", White, King),
+            ("a2", White, Pawn),
+            ("a8", Black, King),
+            ("e8", Black, Rook),
+        ]);
+        assert_illegal(&board, &[("a2", "a3")]);
+    }
+
+    #[test]
+    fn promotion() {
+        let mut board = position(&[
+            ("e1", White, King),
+            ("a7", White, Pawn),
+            ("h8", Black, King),
+            ("b8", Black, Knight),
+        ]);
+        assert!(board.is_legal(square("a7"), square("b8")));
+        board.apply(square("a7"), square("b8"), Some(Knight));
+        assert_eq!(board.get(square("b8")), Some((White, Knight)));
+        assert_eq!(last_rank(White), 7);
+        assert_eq!(last_rank(Black), 0);
+    }
+
+    #[test]
+    fn endings() {
+        let board = play(&[("f2", "f3"), ("e7", "e5"), ("g2", "g4"), ("d8", "h4")]);
+        assert_eq!(board.ending(White), Some(Ending::Checkmate));
+        assert_eq!(board.ending(Black), None);
+
+        let board = position(&[
+            ("c6", White, King),
+            ("b6", White, Queen),
+            ("a8", Black, King),
+        ]);
+        assert_eq!(board.ending(Black), Some(Ending::Stalemate));
+        assert_eq!(Board::default().ending(White), None);
+    }
+}
@@ -0,0 +1,93 @@
+//! The RPC byte stream carried in the binary messages of a WebSocket, for browsers and proxies
+//! that don't let raw TCP through.
+
+use futures::{ready, Sink, SinkExt, StreamExt};
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::WebSocketStream;
+
+/// Reads and writes a WebSocket as a byte stream, to give it to a `twoparty::VatNetwork`.
+pub struct WebSocketIo<S> {
+    websocket: WebSocketStream<S>,
+    /// Last message received
+    incoming: Vec<u8>,
+    /// Bytes of the last message already read
+    read: usize,
+}
+
+impl<S> WebSocketIo<S> {
+    pub fn new(websocket: WebSocketStream<S>) -> Self {
+        Self {
+            websocket,
+            incoming: Vec::new(),
+            read: 0,
+        }
+    }
+}
+
+fn io_error(err: tungstenite::Error) -> io::Error {
+    match err {
+        tungstenite::Error::Io(err) => err,
+        err => io::Error::other(err),
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> futures::AsyncRead for WebSocketIo<S> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        loop {
+            if self.read < self.incoming.len() {
+                let start = self.read;
+                let len = buf.len().min(self.incoming.len() - start);
+                buf[..len].copy_from_slice(&self.incoming[start..start + len]);
+                self.read += len;
+                return Poll::Ready(Ok(len));
+            }
+            match ready!(self.websocket.poll_next_unpin(cx)) {
+                Some(Ok(Message::Binary(data))) => {
+                    self.incoming = data;
+                    self.read = 0;
+                }
+                Some(Ok(Message::Text(_))) => {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "The protocol is only carried in binary messages",
+                    )))
+                }
+                // Pings are answered by tungstenite
+                Some(Ok(Message::Ping(_) | Message::Pong(_) | Message::Frame(_))) => {}
+                Some(Ok(Message::Close(_))) | None => return Poll::Ready(Ok(0)),
+                Some(Err(err)) => return Poll::Ready(Err(io_error(err))),
+            }
+        }
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> futures::AsyncWrite for WebSocketIo<S> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        ready!(self.websocket.poll_ready_unpin(cx)).map_err(io_error)?;
+        // The RPC system flushes after each of its messages
+        Pin::new(&mut self.websocket)
+            .start_send(Message::Binary(buf.to_vec()))
+            .map_err(io_error)?;
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.websocket.poll_flush_unpin(cx).map_err(io_error)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.websocket.poll_close_unpin(cx).map_err(io_error)
+    }
+}
@@ -0,0 +1,258 @@
+use capnp::Error;
+use fluffy_chess_protocol::fluffy_chess_capnp::{move_, piece, Color};
+use fluffy_chess_protocol::rules::{last_rank, Board};
+use fluffy_chess_protocol::{FromReader, ToBuilder};
+
+/// A move as a player sends it, before it is checked against the game.
+#[derive(Clone, Copy)]
+pub struct ProposedMove {
+    from: (u8, u8),
+    to: (u8, u8),
+    promotion: Option<piece::Type>,
+    /// Number of moves played before this one
+    number: u32,
+}
+
+impl<'a> FromReader<'a> for ProposedMove {
+    type Reader = move_::Reader<'a>;
+
+    fn from_reader(reader: move_::Reader<'a>) -> Result<Self, Error> {
+        Ok(Self {
+            from: <(u8, u8)>::from_reader(reader.get_from()?)?,
+            to: <(u8, u8)>::from_reader(reader.get_to()?)?,
+            promotion: match reader.get_promotion().which()? {
+                move_::promotion::None(()) => None,
+                move_::promotion::Piece(piece_type) => Some(piece_type?),
+            },
+            number: reader.get_number(),
+        })
+    }
+}
+
+/// A move kept with its game, to replay the game to spectators joining late.
+#[derive(Clone, Copy)]
+pub struct GameMove {
+    pub number: u32,
+    pub color: Color,
+    pub piece_type: piece::Type,
+    pub from: (u8, u8),
+    pub to: (u8, u8),
+    /// Piece the pawn became on the last rank
+    pub promotion: Option<piece::Type>,
+}
+
+impl<'a> ToBuilder<'a> for GameMove {
+    type Builder = move_::Builder<'a>;
+
+    fn to_builder(&self, mut builder: move_::Builder<'a>) {
+        self.from.to_builder(builder.reborrow().init_from());
+        self.to.to_builder(builder.reborrow().init_to());
+        match self.promotion {
+            Some(piece_type) => builder.reborrow().init_promotion().set_piece(piece_type),
+            None => builder.reborrow().init_promotion().set_none(()),
+        }
+        builder.set_number(self.number);
+    }
+}
+
+impl ProposedMove {
+    /// Check that the move, played by `color`, fits the position on `board`, reached after `ply`
+    /// moves. Returns the move with the piece it plays.
+    pub fn check(self, board: &Board, color: Color, ply: u32) -> Result<GameMove, Error> {
+        if self.number != ply {
+            return Err(Error::failed(format!(
+                "Expected move number {ply} but got {}",
+                self.number
+            )));
+        }
+        let (from, to) = (self.from, self.to);
+        if [from.0, from.1, to.0, to.1]
+            .iter()
+            .any(|&coordinate| coordinate >= 8)
+        {
+            return Err(Error::failed("The move leaves the board".to_string()));
+        }
+        let piece_type = match board.get(from) {
+            Some((piece_color, piece_type)) if piece_color == color => piece_type,
+            _ => {
+                return Err(Error::failed(
+                    "None of your pieces is on that square".to_string(),
+                ))
+            }
+        };
+        if from == to
+            || board
+                .get(to)
+                .is_some_and(|(piece_color, _)| piece_color == color)
+        {
+            return Err(Error::failed("You can't take your own piece".to_string()));
+        }
+        if !board.can_reach(from, to) {
+            return Err(Error::failed(
+                if board.is_castling(from, to) {
+                    "Castling needs the king and the rook unmoved, the squares between them empty \
+                    and the king not in check, nor passing through or landing on an attacked \
+                    square"
+                } else {
+                    "That piece can't move there"
+                }
+                .to_string(),
+            ));
+        }
+
+        let promoted = piece_type == piece::Type::Pawn && to.0 == last_rank(color);
+        let promotion = match (promoted, self.promotion) {
+            // Pawns become queens unless told otherwise
+            (true, None) => Some(piece::Type::Queen),
+            (true, Some(promotion))
+                if matches!(
+                    promotion,
+                    piece::Type::Queen
+                        | piece::Type::Rook
+                        | piece::Type::Bishop
+                        | piece::Type::Knight
+                ) =>
+            {
+                Some(promotion)
+            }
+            (true, Some(_)) => {
+                return Err(Error::failed(
+                    "A pawn can't become a king or stay a pawn".to_string(),
+                ))
+            }
+            (false, None) => None,
+            (false, Some(_)) => {
+                return Err(Error::failed(
+                    "Only a pawn reaching the last rank is promoted".to_string(),
+                ))
+            }
+        };
+        if board.leaves_king_in_check(from, to) {
+            return Err(Error::failed(
+                "Your king would be in check after this move".to_string(),
+            ));
+        }
+        Ok(GameMove {
+            number: self.number,
+            color,
+            piece_type,
+            from,
+            to,
+            promotion,
+        })
+    }
+}
+
+impl GameMove {
+    /// Play the move, checked against `board`, on it.
+    pub fn play(&self, board: &mut Board) {
+        board.apply(self.from, self.to, self.promotion);
+    }
+}
+
+/// Position after these moves.
+pub fn position_after(moves: &[GameMove]) -> Board {
+    let mut board = Board::default();
+    for game_move in moves {
+        game_move.play(&mut board);
+    }
+    board
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fluffy_chess_protocol::rules::{position, square};
+    use piece::Type::{King, Knight, Pawn, Queen, Rook};
+    use Color::{Black, White};
+
+    fn propose(
+        board: &Board,
+        color: Color,
+        number: u32,
+        from: &str,
+        to: &str,
+        promotion: Option<piece::Type>,
+    ) -> Result<GameMove, Error> {
+        let proposed = ProposedMove {
+            from: square(from),
+            to: square(to),
+            promotion,
+            number,
+        };
+        proposed.check(board, color, number)
+    }
+
+    #[test]
+    fn moves_are_checked() {
+        let board = Board::default();
+        let game_move = propose(&board, White, 0, "g1", "f3", None).unwrap();
+        assert_eq!(game_move.piece_type, Knight);
+        assert_eq!(game_move.color, White);
+
+        let proposed = ProposedMove {
+            from: square("e2"),
+            to: square("e4"),
+            promotion: None,
+            number: 1,
+        };
+        assert!(proposed.check(&board, White, 0).is_err());
+        assert!(propose(&board, Black, 0, "e2", "e4", None).is_err());
+        assert!(propose(&board, White, 0, "e7", "e5", None).is_err());
+        assert!(propose(&board, White, 0, "a1", "a2", None).is_err());
+        assert!(propose(&board, White, 0, "e2", "e5", None).is_err());
+        assert!(propose(&board, White, 0, "e2", "e4", Some(Queen)).is_err());
+
+        let pinned = position(&[
+            ("e1", White, King),
+            ("e2", White, Rook),
+            ("a8", Black, King),
+            ("e8", Black, Rook),
+        ]);
+        assert!(propose(&pinned, White, 0, "e2", "d2", None).is_err());
+        assert!(propose(&pinned, White, 0, "e2", "e8", None).is_ok());
+    }
+
+    #[test]
+    fn promotion() {
+        let board = position(&[
+            ("e1", White, King),
+            ("a7", White, Pawn),
+            ("h8", Black, King),
+            ("b8", Black, Knight),
+        ]);
+        assert_eq!(
+            propose(&board, White, 0, "a7", "a8", None)
+                .unwrap()
+                .promotion,
+            Some(Queen)
+        );
+        assert_eq!(
+            propose(&board, White, 0, "a7", "b8", Some(Knight))
+                .unwrap()
+                .promotion,
+            Some(Knight)
+        );
+        assert!(propose(&board, White, 0, "a7", "a8", Some(King)).is_err());
+        assert!(propose(&board, White, 0, "a7", "a8", Some(Pawn)).is_err());
+    }
+
+    #[test]
+    fn positions_replay_moves() {
+        let mut board = Board::default();
+        let mut moves = Vec::new();
+        for (number, (from, to)) in [("e2", "e4"), ("e7", "e5"), ("e1", "e2")]
+            .into_iter()
+            .enumerate()
+        {
+            let color = if number % 2 == 0 { White } else { Black };
+            let game_move = propose(&board, color, number as u32, from, to, None).unwrap();
+            game_move.play(&mut board);
+            moves.push(game_move);
+        }
+        let replayed = position_after(&moves);
+        assert_eq!(replayed.get(square("e2")), Some((White, King)));
+        assert_eq!(replayed.get(square("e5")), Some((Black, Pawn)));
+        assert_eq!(replayed.get(square("e1")), None);
+    }
+}
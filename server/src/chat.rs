@@ -0,0 +1,105 @@
+use std::collections::{HashSet, VecDeque};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Longest chat message accepted, in characters
+const MAX_MESSAGE_LENGTH: usize = 500;
+/// Most messages a player can send within RATE_WINDOW
+const MAX_MESSAGES: usize = 5;
+const RATE_WINDOW: Duration = Duration::from_secs(10);
+
+/// Server-wide check of chat messages, before they are saved and sent.
+pub trait WordFilter {
+    /// The message to send in place of `message`, or why it is refused.
+    fn filter(&self, message: &str) -> Result<String, String>;
+}
+
+/// Lets every message through.
+pub struct NoFilter;
+
+impl WordFilter for NoFilter {
+    fn filter(&self, message: &str) -> Result<String, String> {
+        Ok(message.to_string())
+    }
+}
+
+/// Masks the words of a list with asterisks, whatever their case.
+pub struct BannedWords {
+    words: HashSet<String>,
+}
+
+impl BannedWords {
+    /// Read the banned words from a file, one per line.
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(Self {
+            words: text
+                .lines()
+                .map(|word| word.trim().to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect(),
+        })
+    }
+}
+
+impl WordFilter for BannedWords {
+    fn filter(&self, message: &str) -> Result<String, String> {
+        let mut filtered = String::with_capacity(message.len());
+        let mut word = String::new();
+        // A last space flushes the last word, it is not added to the message
+        for c in message.chars().chain(std::iter::once(' ')) {
+            if c.is_alphanumeric() {
+                word.push(c);
+                continue;
+            }
+            if self.words.contains(&word.to_lowercase()) {
+                filtered.extend(word.chars().map(|_| '*'));
+            } else {
+                filtered.push_str(&word);
+            }
+            word.clear();
+            filtered.push(c);
+        }
+        filtered.pop();
+        Ok(filtered)
+    }
+}
+
+/// The message without surrounding spaces, if it is neither empty nor too long.
+pub fn check_length(message: &str) -> Result<&str, String> {
+    let message = message.trim();
+    if message.is_empty() {
+        return Err("The message is empty".to_string());
+    }
+    if message.chars().count() > MAX_MESSAGE_LENGTH {
+        return Err(format!(
+            "Messages can't be longer than {MAX_MESSAGE_LENGTH} characters"
+        ));
+    }
+    Ok(message)
+}
+
+/// Keeps a player from sending more than MAX_MESSAGES within RATE_WINDOW.
+#[derive(Default)]
+pub struct ChatLimiter {
+    /// When the last messages were sent, oldest first
+    sent: VecDeque<Instant>,
+}
+
+impl ChatLimiter {
+    /// Count a message sent at `now`, unless too many were sent recently.
+    pub fn check(&mut self, now: Instant) -> Result<(), String> {
+        while self
+            .sent
+            .front()
+            .is_some_and(|sent| now.duration_since(*sent) >= RATE_WINDOW)
+        {
+            self.sent.pop_front();
+        }
+        if self.sent.len() >= MAX_MESSAGES {
+            return Err("Too many messages, wait a moment before sending another".to_string());
+        }
+        self.sent.push_back(now);
+        Ok(())
+    }
+}
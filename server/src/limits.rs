@@ -0,0 +1,380 @@
+use capnp::Error;
+use futures::{AsyncRead, AsyncWrite, Future};
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+/// Calls a connection can make at once, then per second
+const CONNECTION_BURST: f64 = 40.;
+const CONNECTION_RATE: f64 = 8.;
+/// Calls a user can make at once over all of their connections, then per second
+const USER_BURST: f64 = 30.;
+const USER_RATE: f64 = 5.;
+/// Most users who can call through a connection, each with a rate of their own
+const MAX_USERS_PER_CONNECTION: usize = 4;
+/// Most games a user can have waiting for an opponent or being played at once
+const MAX_OPEN_GAMES: usize = 5;
+/// Largest message read from a client, in words of 8 bytes
+pub const MAX_MESSAGE_WORDS: usize = 64 * 1024;
+/// Connections where nothing goes either way for this long are closed. Long enough for a player
+/// to think about a move.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+/// Connections that take longer to finish their TLS or WebSocket handshake are closed
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// How often the metrics are logged, when they changed
+const METRICS_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Allows `burst` calls at once, then refills at `rate` calls per second.
+struct TokenBucket {
+    tokens: f64,
+    burst: f64,
+    rate: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(burst: f64, rate: f64, now: Instant) -> Self {
+        Self {
+            tokens: burst,
+            burst,
+            rate,
+            updated: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+        self.updated = now;
+    }
+
+    /// Take a token at `now`, returns false when there is none left.
+    fn take(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        if self.tokens < 1. {
+            return false;
+        }
+        self.tokens -= 1.;
+        true
+    }
+
+    /// Whether the bucket is full again at `now`, it is no different from a new one then.
+    fn is_full(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        self.tokens >= self.burst
+    }
+}
+
+/// Calls refused and connections closed by the limits, since the server started.
+#[derive(Default)]
+pub struct Metrics {
+    connection_rate_limited: Cell<u64>,
+    user_rate_limited: Cell<u64>,
+    /// Calls refused for naming more users than a connection can have
+    users_refused: Cell<u64>,
+    open_games_refused: Cell<u64>,
+    idle_closed: Cell<u64>,
+    /// Connections that sent a message too large or not readable
+    unreadable_closed: Cell<u64>,
+    handshake_timed_out: Cell<u64>,
+}
+
+impl Metrics {
+    fn count(counter: &Cell<u64>) {
+        counter.set(counter.get() + 1);
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "Limits: {} calls over the connection rate, {} calls over the user rate, {} calls \
+            from too many users, {} games refused, {} idle connections closed, {} unreadable connections closed, {} \
+            handshakes timed out",
+            self.connection_rate_limited.get(),
+            self.user_rate_limited.get(),
+            self.users_refused.get(),
+            self.open_games_refused.get(),
+            self.idle_closed.get(),
+            self.unreadable_closed.get(),
+            self.handshake_timed_out.get()
+        )
+    }
+
+    /// Log the metrics every METRICS_INTERVAL when they changed, until the server stops.
+    pub async fn report(self: Rc<Self>) {
+        let mut reported = Self::default().summary();
+        loop {
+            tokio::time::sleep(METRICS_INTERVAL).await;
+            let summary = self.summary();
+            if summary != reported {
+                tracing::info!("{summary}");
+                reported = summary;
+            }
+        }
+    }
+}
+
+/// Rate limit of a connection and of the users calling through it, checked by every capability
+/// the connection gets.
+///
+/// Users are the names the client gives, which nothing authenticates yet. A connection could
+/// name a new user for every call to get a fresh rate each time, so it may only name
+/// MAX_USERS_PER_CONNECTION of them, and the connection rate bounds it in any case.
+#[derive(Clone)]
+pub struct Limiter {
+    connection: Rc<RefCell<TokenBucket>>,
+    /// Users who called through the connection
+    users_seen: Rc<RefCell<HashSet<String>>>,
+    /// Shared by every connection
+    users: Rc<RefCell<HashMap<String, TokenBucket>>>,
+    metrics: Rc<Metrics>,
+}
+
+impl Limiter {
+    pub fn new(metrics: Rc<Metrics>) -> Self {
+        Self {
+            connection: Rc::new(RefCell::new(TokenBucket::new(
+                CONNECTION_BURST,
+                CONNECTION_RATE,
+                Instant::now(),
+            ))),
+            users_seen: Default::default(),
+            users: Default::default(),
+            metrics,
+        }
+    }
+
+    /// The limiter of another connection, sharing the rates of the users.
+    pub fn for_connection(&self) -> Self {
+        Self {
+            connection: Rc::new(RefCell::new(TokenBucket::new(
+                CONNECTION_BURST,
+                CONNECTION_RATE,
+                Instant::now(),
+            ))),
+            users_seen: Default::default(),
+            users: self.users.clone(),
+            metrics: self.metrics.clone(),
+        }
+    }
+
+    /// Count a call of the connection made by `user` when it is known, or refuse it when either
+    /// calls too often.
+    pub fn check(&self, user: Option<&str>) -> Result<(), Error> {
+        let now = Instant::now();
+        if !self.connection.borrow_mut().take(now) {
+            Metrics::count(&self.metrics.connection_rate_limited);
+            return Err(Error::overloaded(
+                "Too many calls on this connection, wait a moment before the next one".to_string(),
+            ));
+        }
+        let Some(user) = user else {
+            return Ok(());
+        };
+        let mut users_seen = self.users_seen.borrow_mut();
+        if !users_seen.contains(user) {
+            if users_seen.len() >= MAX_USERS_PER_CONNECTION {
+                Metrics::count(&self.metrics.users_refused);
+                return Err(Error::failed(format!(
+                    "This connection already called as {MAX_USERS_PER_CONNECTION} users, \
+                    reconnect to call as another one"
+                )));
+            }
+            users_seen.insert(user.to_string());
+        }
+        let mut users = self.users.borrow_mut();
+        if !users.contains_key(user) {
+            // Forget the users who have not called for a while before adding one
+            users.retain(|_, bucket| !bucket.is_full(now));
+        }
+        let bucket = users
+            .entry(user.to_string())
+            .or_insert_with(|| TokenBucket::new(USER_BURST, USER_RATE, now));
+        if !bucket.take(now) {
+            Metrics::count(&self.metrics.user_rate_limited);
+            return Err(Error::overloaded(format!(
+                "Too many calls from {user}, wait a moment before the next one"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Refuse another game to a user who has `open` games already, when that is too many.
+    pub fn check_open_games(&self, open: usize) -> Result<(), Error> {
+        if open >= MAX_OPEN_GAMES {
+            Metrics::count(&self.metrics.open_games_refused);
+            return Err(Error::failed(format!(
+                "You already have {MAX_OPEN_GAMES} games open, finish or abort one first"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Count a connection closed because it could not be read.
+    pub fn count_unreadable(&self) {
+        Metrics::count(&self.metrics.unreadable_closed);
+    }
+
+    /// Run a handshake, or give up on it after HANDSHAKE_TIMEOUT, counting it as timed out.
+    pub async fn handshake<T>(&self, handshake: impl Future<Output = T>) -> Option<T> {
+        let finished = tokio::time::timeout(HANDSHAKE_TIMEOUT, handshake)
+            .await
+            .ok();
+        if finished.is_none() {
+            Metrics::count(&self.metrics.handshake_timed_out);
+        }
+        finished
+    }
+
+    /// Resolves once nothing went through a connection for IDLE_TIMEOUT, counting it as closed.
+    pub async fn idle(&self, last_active: Rc<Cell<Instant>>) {
+        loop {
+            let deadline = last_active.get() + IDLE_TIMEOUT;
+            if Instant::now() >= deadline {
+                Metrics::count(&self.metrics.idle_closed);
+                return;
+            }
+            tokio::time::sleep_until(deadline.into()).await;
+        }
+    }
+}
+
+/// A half of a connection that records when data last went through it.
+pub struct Tracked<T> {
+    inner: T,
+    last_active: Rc<Cell<Instant>>,
+}
+
+impl<T> Tracked<T> {
+    pub fn new(inner: T, last_active: Rc<Cell<Instant>>) -> Self {
+        Self { inner, last_active }
+    }
+
+    fn record(&self, poll: &Poll<io::Result<usize>>) {
+        if let Poll::Ready(Ok(1..)) = poll {
+            self.last_active.set(Instant::now());
+        }
+    }
+}
+
+impl<T: AsyncRead + Unpin> AsyncRead for Tracked<T> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let poll = Pin::new(&mut self.inner).poll_read(cx, buf);
+        self.record(&poll);
+        poll
+    }
+}
+
+impl<T: AsyncWrite + Unpin> AsyncWrite for Tracked<T> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let poll = Pin::new(&mut self.inner).poll_write(cx, buf);
+        self.record(&poll);
+        poll
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_close(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_allows_a_burst_then_refills() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(3., 2., start);
+        assert!((0..3).all(|_| bucket.take(start)));
+        assert!(!bucket.take(start));
+
+        // Two tokens a second, one after half of it
+        assert!(!bucket.take(start + Duration::from_millis(400)));
+        assert!(bucket.take(start + Duration::from_millis(500)));
+        assert!(!bucket.take(start + Duration::from_millis(500)));
+
+        // Never more than the burst, however long it waited
+        let later = start + Duration::from_secs(60);
+        assert!(bucket.is_full(later));
+        assert!((0..3).all(|_| bucket.take(later)));
+        assert!(!bucket.take(later));
+    }
+
+    #[test]
+    fn connection_is_limited() {
+        let limiter = Limiter::new(Default::default());
+        for _ in 0..CONNECTION_BURST as usize {
+            limiter.check(None).unwrap();
+        }
+        assert!(limiter.check(None).is_err());
+        assert_eq!(limiter.metrics.connection_rate_limited.get(), 1);
+
+        // Other connections have their own rate
+        limiter.for_connection().check(None).unwrap();
+    }
+
+    #[test]
+    fn user_is_limited_over_all_connections() {
+        let limiter = Limiter::new(Default::default());
+        let other_connection = limiter.for_connection();
+        for call in 0..USER_BURST as usize {
+            let connection = if call % 2 == 0 {
+                &limiter
+            } else {
+                &other_connection
+            };
+            connection.check(Some("alice")).unwrap();
+        }
+        assert!(limiter.check(Some("alice")).is_err());
+        assert!(limiter.for_connection().check(Some("alice")).is_err());
+        assert_eq!(limiter.metrics.user_rate_limited.get(), 2);
+
+        // Other users have their own rate
+        limiter.check(Some("bob")).unwrap();
+    }
+
+    #[test]
+    fn connection_names_few_users() {
+        let limiter = Limiter::new(Default::default());
+        let users: Vec<String> = (0..=MAX_USERS_PER_CONNECTION)
+            .map(|user| format!("user{user}"))
+            .collect();
+        for user in &users[..MAX_USERS_PER_CONNECTION] {
+            limiter.check(Some(user)).unwrap();
+        }
+        assert!(limiter
+            .check(Some(&users[MAX_USERS_PER_CONNECTION]))
+            .is_err());
+        assert_eq!(limiter.metrics.users_refused.get(), 1);
+
+        // The users it named can still call, and other connections can name the others
+        limiter.check(Some(&users[0])).unwrap();
+        limiter
+            .for_connection()
+            .check(Some(&users[MAX_USERS_PER_CONNECTION]))
+            .unwrap();
+    }
+
+    #[test]
+    fn open_games_are_limited() {
+        let limiter = Limiter::new(Default::default());
+        limiter.check_open_games(MAX_OPEN_GAMES - 1).unwrap();
+        assert!(limiter.check_open_games(MAX_OPEN_GAMES).is_err());
+        assert_eq!(limiter.metrics.open_games_refused.get(), 1);
+    }
+}
@@ -1,31 +1,444 @@
-use crate::fluffy_chess_capnp::{
-    game_config, game_maker,
-    game_maker::{FindGameParams, FindGameResults, ResumeGameParams, ResumeGameResults},
-    game_side,
-    game_side::{ColorParams, ColorResults, IdParams, IdResults, MoveParams, MoveResults},
-};
+use crate::board::{position_after, GameMove, ProposedMove};
+use crate::chat::{BannedWords, ChatLimiter, NoFilter, WordFilter};
+use crate::limits::{Limiter, Metrics, Tracked};
+use crate::profile::ProfileImpl;
+use crate::rating::{rating_window, TimeControl};
+use crate::social::{Presence, SocialImpl};
+use capnp::any_pointer;
 use capnp::capability::Promise;
-use capnp::{Error, ErrorKind};
-use capnp_rpc::{rpc_twoparty_capnp, twoparty, RpcSystem};
+use capnp::traits::HasTypeId;
+use capnp::Error;
+use capnp_rpc::{pry, rpc_twoparty_capnp, twoparty, RpcSystem};
 use clap::Parser;
+use fluffy_chess_protocol::fluffy_chess_capnp::{
+    challenge,
+    challenge::{AcceptParams, AcceptResults, DeclineParams, DeclineResults},
+    game_config, game_directory,
+    game_directory::{GamesParams, GamesResults, WatchParams, WatchResults},
+    game_maker,
+    game_maker::{
+        ChallengeParams, ChallengeResults, DirectoryParams, DirectoryResults, FindGameParams,
+        FindGameResults, ProfileParams, ProfileResults, ResumeGameParams, ResumeGameResults,
+        SocialParams, SocialResults,
+    },
+    game_result, game_side,
+    game_side::{
+        AbortParams, AbortResults, AcceptDrawParams, AcceptDrawResults, ChatParams, ChatResults,
+        ColorParams, ColorResults, DeclineDrawParams, DeclineDrawResults, IdParams, IdResults,
+        MoveParams, MoveResults, OfferDrawParams, OfferDrawResults, RequestTakebackParams,
+        RequestTakebackResults, ResignParams, ResignResults,
+    },
+    handshake,
+    handshake::{HelloParams, HelloResults},
+    player, spectator, Color, PROTOCOL_VERSION,
+};
 
-use futures::{AsyncReadExt, FutureExt, StreamExt};
+use fluffy_chess_protocol::rules::Ending;
+use fluffy_chess_protocol::websocket::WebSocketIo;
+use fluffy_chess_protocol::{FromReader, ToBuilder};
+use futures::channel::oneshot;
+use futures::{future, AsyncReadExt};
 use serde::{Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::io;
 use std::net::{SocketAddr, ToSocketAddrs};
+use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use surrealdb::engine::local::Mem;
 use surrealdb::{Connection, Surreal};
+use tokio_rustls::TlsAcceptor;
+use tracing::{error, info, warn};
+use tracing_subscriber::EnvFilter;
+
+mod board;
+mod chat;
+mod limits;
+mod profile;
+mod rating;
+mod social;
+mod tls;
+
+/// How long a challenged user has to answer
+const CHALLENGE_TIMEOUT: Duration = Duration::from_secs(60);
+
+fn db_error(err: surrealdb::Error) -> Error {
+    Error::failed(err.to_string())
+}
+
+/// Seconds since the Unix epoch
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+fn opposite(color: Color) -> Color {
+    match color {
+        Color::White => Color::Black,
+        Color::Black => Color::White,
+    }
+}
+
+/// Who won a game.
+#[derive(Clone, Copy, Serialize)]
+enum Outcome {
+    WhiteWins,
+    BlackWins,
+    Draw,
+    /// The game was aborted, it doesn't count
+    NoResult,
+}
+
+impl Outcome {
+    /// Score of white for the ratings, `None` if the game doesn't count.
+    fn white_score(self) -> Option<f64> {
+        match self {
+            Outcome::WhiteWins => Some(1.),
+            Outcome::BlackWins => Some(0.),
+            Outcome::Draw => Some(0.5),
+            Outcome::NoResult => None,
+        }
+    }
+}
+
+/// Why a game ended.
+#[derive(Clone, Copy, Serialize)]
+enum Reason {
+    Resignation,
+    DrawAgreed,
+    Aborted,
+    Checkmate,
+    Stalemate,
+    /// The connection of the loser closed
+    Abandoned,
+}
+
+/// How a game ended.
+#[derive(Clone, Copy)]
+struct GameResult {
+    outcome: Outcome,
+    reason: Reason,
+}
+
+fn win_for(winner: Color) -> Outcome {
+    match winner {
+        Color::White => Outcome::WhiteWins,
+        Color::Black => Outcome::BlackWins,
+    }
+}
+
+impl GameResult {
+    fn resignation(loser: Color) -> Self {
+        Self {
+            outcome: win_for(opposite(loser)),
+            reason: Reason::Resignation,
+        }
+    }
+
+    fn ending(ending: Ending, mover: Color) -> Self {
+        match ending {
+            Ending::Checkmate => Self {
+                outcome: win_for(mover),
+                reason: Reason::Checkmate,
+            },
+            Ending::Stalemate => Self {
+                outcome: Outcome::Draw,
+                reason: Reason::Stalemate,
+            },
+        }
+    }
+
+    fn abandoned(loser: Color) -> Self {
+        Self {
+            outcome: win_for(opposite(loser)),
+            reason: Reason::Abandoned,
+        }
+    }
+
+    fn draw_agreed() -> Self {
+        Self {
+            outcome: Outcome::Draw,
+            reason: Reason::DrawAgreed,
+        }
+    }
+
+    fn aborted() -> Self {
+        Self {
+            outcome: Outcome::NoResult,
+            reason: Reason::Aborted,
+        }
+    }
+
+    fn write(&self, mut builder: game_result::Builder) {
+        builder.set_outcome(match self.outcome {
+            Outcome::WhiteWins => game_result::Outcome::WhiteWins,
+            Outcome::BlackWins => game_result::Outcome::BlackWins,
+            Outcome::Draw => game_result::Outcome::Draw,
+            Outcome::NoResult => game_result::Outcome::NoResult,
+        });
+        builder.set_reason(match self.reason {
+            Reason::Resignation => game_result::Reason::Resignation,
+            Reason::DrawAgreed => game_result::Reason::DrawAgreed,
+            Reason::Aborted => game_result::Reason::Aborted,
+            Reason::Checkmate => game_result::Reason::Checkmate,
+            Reason::Stalemate => game_result::Reason::Stalemate,
+            Reason::Abandoned => game_result::Reason::Abandoned,
+        });
+    }
+}
+
+/// A game being played, shared by both of its sides.
+struct Game {
+    /// Players indexed by the color they play
+    players: [Option<player::Client>; 2],
+    /// Names of the players, indexed by color
+    usernames: [Option<String>; 2],
+    /// Moves played so far
+    moves: Vec<GameMove>,
+    /// Watchers of the game, by an ID to remove them once they can't be reached
+    spectators: HashMap<u64, spectator::Client>,
+    next_spectator: u64,
+    /// Color of the player whose draw offer waits for an answer
+    draw_offer: Option<Color>,
+    /// How the game ended, once it is over
+    result: Option<GameResult>,
+    /// Whether the game changes the ratings of the players
+    rated: bool,
+    time_control: TimeControl,
+}
+
+impl Game {
+    /// Number of moves played
+    fn ply(&self) -> u32 {
+        self.moves.len() as u32
+    }
+
+    fn turn(&self) -> Color {
+        if self.ply() % 2 == 0 {
+            Color::White
+        } else {
+            Color::Black
+        }
+    }
+
+    fn opponent(&self, color: Color) -> Result<player::Client, Error> {
+        self.players[opposite(color) as usize]
+            .clone()
+            .ok_or_else(|| Error::failed("No opponent has joined the game yet".to_string()))
+    }
+
+    fn check_playing(&self) -> Result<(), Error> {
+        match self.result {
+            Some(_) => Err(Error::failed("The game is over".to_string())),
+            None => Ok(()),
+        }
+    }
+
+    fn check_draw_offered_to(&self, color: Color) -> Result<(), Error> {
+        if self.draw_offer != Some(opposite(color)) {
+            return Err(Error::failed(
+                "Your opponent has not offered a draw".to_string(),
+            ));
+        }
+        Ok(())
+    }
 
-mod fluffy_chess_capnp {
-    include!("../proto/fluffy_chess_capnp.rs");
+    fn end(&mut self, result: GameResult) {
+        self.result = Some(result);
+        self.draw_offer = None;
+    }
+
+    fn add_spectator(&mut self, spectator: spectator::Client) {
+        self.spectators.insert(self.next_spectator, spectator);
+        self.next_spectator += 1;
+    }
+}
+
+/// Games in progress on this server, forgotten once their result is saved.
+#[derive(Default)]
+struct Games {
+    next_id: u64,
+    games: HashMap<u64, Game>,
+}
+
+impl Games {
+    fn get_mut(&mut self, id: u64) -> Result<&mut Game, Error> {
+        self.games
+            .get_mut(&id)
+            .ok_or_else(|| Error::failed(format!("No game with ID {id}, or it is over")))
+    }
+
+    /// Start a new game where `player` plays white.
+    fn create(&mut self, player: player::Client, game_config: &GameConfig) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.games.insert(
+            id,
+            Game {
+                players: [Some(player), None],
+                usernames: [Some(game_config.user.to_string()), None],
+                moves: Vec::new(),
+                spectators: HashMap::new(),
+                next_spectator: 0,
+                draw_offer: None,
+                result: None,
+                rated: game_config.rated,
+                time_control: game_config.time_control,
+            },
+        );
+        id
+    }
+
+    /// Number of games `username` waits for an opponent in or plays.
+    fn open_games(&self, username: &str) -> usize {
+        self.games
+            .values()
+            .filter(|game| {
+                game.result.is_none()
+                    && game.usernames.iter().flatten().any(|name| name == username)
+            })
+            .count()
+    }
+
+    /// Join a game as black, returns `None` if the game is already full or over.
+    fn join(&mut self, id: u64, player: player::Client, username: &str) -> Option<Color> {
+        let game = self.games.get_mut(&id)?;
+        let side = &mut game.players[Color::Black as usize];
+        if side.is_some() || game.result.is_some() {
+            return None;
+        }
+        *side = Some(player);
+        game.usernames[Color::Black as usize] = Some(username.to_string());
+        Some(Color::Black)
+    }
+}
+
+/// Games played over a connection, by ID with the color played, ended when the connection closes.
+type ConnectionGames = Rc<RefCell<HashMap<u64, Color>>>;
+
+/// Tell the spectators of a game what happened, forgetting those that can't be reached.
+fn notify_spectators(
+    games: &Rc<RefCell<Games>>,
+    id: u64,
+    request: impl Fn(&spectator::Client) -> Promise<(), Error>,
+) -> Result<(), Error> {
+    let spectators: Vec<(u64, spectator::Client)> = games
+        .borrow_mut()
+        .get_mut(id)?
+        .spectators
+        .iter()
+        .map(|(spectator_id, spectator)| (*spectator_id, spectator.clone()))
+        .collect();
+    for (spectator_id, spectator) in spectators {
+        let sent = request(&spectator);
+        let games = games.clone();
+        // Don't hold the players up while the spectators receive the move
+        tokio::task::spawn_local(async move {
+            if sent.await.is_err() {
+                if let Ok(game) = games.borrow_mut().get_mut(id) {
+                    game.spectators.remove(&spectator_id);
+                }
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Save how a game ended and rate it, then tell its spectators and the `notified` players and
+/// forget the game.
+async fn save_result<C: Connection>(
+    db: &Surreal<C>,
+    games: &Rc<RefCell<Games>>,
+    id: u64,
+    ply: u32,
+    result: GameResult,
+    notified: Vec<player::Client>,
+) -> Result<(), Error> {
+    db.query("CREATE result CONTENT $result")
+        .bind((
+            "result",
+            SavedResult {
+                game: id,
+                ply,
+                outcome: result.outcome,
+                reason: result.reason,
+            },
+        ))
+        .await
+        .map_err(db_error)?;
+
+    let rated = {
+        let mut games = games.borrow_mut();
+        let game = games.get_mut(id)?;
+        match (&game.usernames, result.outcome.white_score()) {
+            ([Some(white), Some(black)], Some(white_score)) if game.rated => Some((
+                [white.clone(), black.clone()],
+                game.time_control,
+                white_score,
+            )),
+            _ => None,
+        }
+    };
+    if let Some(([white, black], time_control, white_score)) = rated {
+        profile::update_ratings(db, id, [&white, &black], time_control, white_score).await?;
+    }
+
+    notify_spectators(games, id, |spectator| {
+        let mut request = spectator.game_ended_request();
+        result.write(request.get().init_result());
+        Promise::from_future(async move {
+            request.send().promise.await?;
+            Ok(())
+        })
+    })?;
+    games.borrow_mut().games.remove(&id);
+
+    for player in notified {
+        let mut request = player.game_ended_request();
+        result.write(request.get().init_result());
+        let sent = request.send().promise;
+        // The game ended even if a player is gone and can't hear it
+        tokio::task::spawn_local(async move {
+            let _ = sent.await;
+        });
+    }
+    Ok(())
 }
 
 struct GameMakerImpl<C: Connection> {
     db: Surreal<C>,
+    games: Rc<RefCell<Games>>,
+    word_filter: Rc<dyn WordFilter>,
+    presence: Rc<RefCell<Presence>>,
+    limiter: Limiter,
+    connection_games: ConnectionGames,
 }
 
 impl<C: Connection> GameMakerImpl<C> {
-    fn new(db: Surreal<C>) -> Self {
-        Self { db }
+    fn new(db: Surreal<C>, word_filter: Rc<dyn WordFilter>, metrics: Rc<Metrics>) -> Self {
+        Self {
+            db,
+            games: Default::default(),
+            word_filter,
+            presence: Default::default(),
+            limiter: Limiter::new(metrics),
+            connection_games: Default::default(),
+        }
+    }
+
+    /// The game maker of a new connection, with a rate limit and games of its own.
+    fn for_connection(&self) -> Self {
+        Self {
+            db: self.db.clone(),
+            games: self.games.clone(),
+            word_filter: self.word_filter.clone(),
+            presence: self.presence.clone(),
+            limiter: self.limiter.for_connection(),
+            connection_games: Default::default(),
+        }
     }
 }
 
@@ -52,6 +465,8 @@ impl<'a> TryFrom<game_config::adversary::Reader<'a>> for Adversary<'a> {
 struct GameConfig<'a> {
     user: &'a str,
     adversary: Adversary<'a>,
+    time_control: TimeControl,
+    rated: bool,
 }
 
 impl<'a> TryFrom<game_config::Reader<'a>> for GameConfig<'a> {
@@ -61,81 +476,865 @@ impl<'a> TryFrom<game_config::Reader<'a>> for GameConfig<'a> {
         Ok(Self {
             user: value.get_user()?.to_str()?,
             adversary: value.get_adversary().try_into()?,
+            time_control: match value.get_timer().which()? {
+                game_config::timer::None(_) => TimeControl::Unlimited,
+                game_config::timer::PerTurn(_) => TimeControl::PerTurn,
+                game_config::timer::PerGame(seconds) => TimeControl::per_game(seconds),
+            },
+            rated: value.get_rated(),
         })
     }
 }
 
+/// A game waiting for an opponent.
+#[derive(Serialize)]
+struct LobbyEntry<'a> {
+    username: &'a str,
+    adversary: Adversary<'a>,
+    game: u64,
+    time_control: TimeControl,
+    rated: bool,
+    /// Rating of the player at the time control of the game
+    rating: f64,
+    /// When the player started waiting, in seconds since the Unix epoch
+    since: u64,
+}
+
+/// A game of the lobby that may fit a player.
+#[derive(Deserialize)]
+struct WaitingGame {
+    game: u64,
+    rating: f64,
+    since: u64,
+}
+
+/// A chat message as it is saved in the database.
+#[derive(Serialize)]
+struct SavedChat {
+    game: u64,
+    /// Number of moves played when the message was sent
+    ply: u32,
+    username: String,
+    message: String,
+    /// Seconds since the Unix epoch
+    sent_at: u64,
+}
+
+/// The end of a game as it is saved in the database.
+#[derive(Serialize)]
+struct SavedResult {
+    game: u64,
+    /// Number of moves played when the game ended
+    ply: u32,
+    outcome: Outcome,
+    reason: Reason,
+}
+
+/// A move as it is saved in the database.
+#[derive(Deserialize, Serialize)]
+struct SavedMove {
+    game: u64,
+    ply: u32,
+    from: (u8, u8),
+    to: (u8, u8),
+    /// Piece the pawn became, by its value in the schema
+    promotion: Option<u16>,
+}
+
 impl<C: Connection> game_maker::Server for GameMakerImpl<C> {
     fn find_game(
         &mut self,
         params: FindGameParams,
         mut results: FindGameResults,
     ) -> Promise<(), Error> {
-        Promise::from_future(async {
-            let game_config: GameConfig = params.get()?.get_game_config()?.try_into()?;
-            let mut query_body = "SELECT game FROM lobby WHERE adversary=$username".to_string();
+        let db = self.db.clone();
+        let games = self.games.clone();
+        let word_filter = self.word_filter.clone();
+        let limiter = self.limiter.clone();
+        let connection_games = self.connection_games.clone();
+        Promise::from_future(async move {
+            let params = params.get()?;
+            let game_config: GameConfig = params.get_game_config()?.try_into()?;
+            limiter.check(Some(game_config.user))?;
+            limiter.check_open_games(games.borrow().open_games(game_config.user))?;
+            let player = params.get_player()?;
+            let rating =
+                profile::load_rating(&db, game_config.user, game_config.time_control).await?;
+            let friends = social::friends_of(&db, game_config.user).await?;
+            // Games for friends only are joined by friends, who are always mutual
+            let mut query_body = "SELECT game, rating, since FROM lobby WHERE username!=$user \
+                AND (adversary='Any' OR adversary.User=$user \
+                OR (adversary='Friends' AND username INSIDE $friends)) \
+                AND time_control=$time_control AND rated=$rated"
+                .to_string();
             match game_config.adversary {
-                Adversary::User(_) => {
-                    query_body.push_str(" AND username=$adversary");
+                Adversary::Any => {}
+                Adversary::Friends => query_body.push_str(" AND username INSIDE $friends"),
+                Adversary::User(_) => query_body.push_str(" AND username=$adversary.User"),
+            }
+            query_body.push_str(" ORDER BY since");
+            let waiting: Vec<WaitingGame> = db
+                .query(query_body)
+                .bind(&game_config)
+                .bind(("friends", &friends))
+                .await
+                .and_then(|mut response| response.take(0))
+                .map_err(db_error)?;
+
+            // Rated games are played between players of a close rating, unless they picked
+            // each other
+            let now = now();
+            let fits = |waiting: &WaitingGame| {
+                !game_config.rated
+                    || matches!(game_config.adversary, Adversary::User(_))
+                    || (waiting.rating - rating.rating).abs()
+                        <= rating_window(Duration::from_secs(now.saturating_sub(waiting.since)))
+            };
+
+            // Join the game waiting the longest for an opponent, or start a new one
+            let joined = waiting.into_iter().filter(fits).find_map(|waiting| {
+                games
+                    .borrow_mut()
+                    .join(waiting.game, player.clone(), game_config.user)
+                    .map(|color| (waiting.game, color))
+            });
+            let (id, color) = match joined {
+                Some((id, color)) => {
+                    connection_games.borrow_mut().insert(id, color);
+                    db.query("DELETE lobby WHERE game=$game")
+                        .bind(("game", id))
+                        .await
+                        .map_err(db_error)?;
+                    (id, color)
                 }
-                _ => {}
+                None => {
+                    let id = games.borrow_mut().create(player, &game_config);
+                    connection_games.borrow_mut().insert(id, Color::White);
+                    db.query("CREATE lobby CONTENT $entry")
+                        .bind((
+                            "entry",
+                            LobbyEntry {
+                                username: game_config.user,
+                                adversary: game_config.adversary,
+                                game: id,
+                                time_control: game_config.time_control,
+                                rated: game_config.rated,
+                                rating: rating.rating,
+                                since: now,
+                            },
+                        ))
+                        .await
+                        .map_err(db_error)?;
+                    (id, Color::White)
+                }
+            };
+            results
+                .get()
+                .set_game_side(capnp_rpc::new_client(GameSideImpl::new(
+                    id,
+                    color,
+                    game_config.user.to_string(),
+                    db,
+                    games,
+                    word_filter,
+                    limiter,
+                )));
+
+            Ok(())
+        })
+    }
+
+    fn resume_game(&mut self, _: ResumeGameParams, _: ResumeGameResults) -> Promise<(), Error> {
+        Promise::err(Error::unimplemented(
+            "resuming games is not supported yet".to_string(),
+        ))
+    }
+
+    fn social(&mut self, params: SocialParams, mut results: SocialResults) -> Promise<(), Error> {
+        let params = pry!(params.get());
+        let user = pry!(pry!(params.get_user()).to_str()).to_string();
+        pry!(self.limiter.check(Some(&user)));
+        let listener = pry!(params.get_listener());
+        results
+            .get()
+            .set_social(capnp_rpc::new_client(SocialImpl::connect(
+                self.db.clone(),
+                self.presence.clone(),
+                user,
+                listener,
+                self.limiter.clone(),
+                self.connection_games.clone(),
+            )));
+        Promise::ok(())
+    }
+
+    fn challenge(
+        &mut self,
+        params: ChallengeParams,
+        mut results: ChallengeResults,
+    ) -> Promise<(), Error> {
+        let db = self.db.clone();
+        let games = self.games.clone();
+        let word_filter = self.word_filter.clone();
+        let presence = self.presence.clone();
+        let limiter = self.limiter.clone();
+        let connection_games = self.connection_games.clone();
+        Promise::from_future(async move {
+            let params = params.get()?;
+            let config_reader = params.get_game_config()?;
+            let game_config: GameConfig = config_reader.try_into()?;
+            limiter.check(Some(game_config.user))?;
+            let Adversary::User(challenged) = game_config.adversary else {
+                return Err(Error::failed("Only a user can be challenged".to_string()));
+            };
+            if challenged == game_config.user {
+                return Err(Error::failed("You can't challenge yourself".to_string()));
             }
-            let mut query = self.db.query(query_body).bind(game_config);
-            if let Ok(mut game) = query.await {
-                //
-                let game_sides: Vec<u64> = game.take(0).map_err(|err| Error {
-                    kind: ErrorKind::Failed,
-                    extra: err.to_string(),
-                })?;
-                results
-                    .get()
-                    .set_game_side(capnp_rpc::new_client(GameSideImpl::new(
-                        *game_sides.first().unwrap(),
-                        self.db.clone(),
-                    )))
+            if !presence.borrow().is_online(challenged) {
+                return Err(Error::failed(format!("{challenged} is not online")));
             }
+            limiter.check_open_games(games.borrow().open_games(game_config.user))?;
 
-            Ok::<(), Error>(())
+            // The game is ready for the challenged user to join it as black
+            let id = games
+                .borrow_mut()
+                .create(params.get_player()?, &game_config);
+            // Leaving before the challenge is answered takes the game back
+            connection_games.borrow_mut().insert(id, Color::White);
+            let (answer, answered) = oneshot::channel();
+            let answer = Rc::new(RefCell::new(Some(answer)));
+            social::notify_sessions(
+                &presence,
+                challenged,
+                |listener, session_limiter, session_games| {
+                    // Each session answers over its own connection, and counts against its limits
+                    let challenge: challenge::Client = capnp_rpc::new_client(ChallengeImpl {
+                        id,
+                        challenged: challenged.to_string(),
+                        answer: answer.clone(),
+                        db: db.clone(),
+                        games: games.clone(),
+                        word_filter: word_filter.clone(),
+                        limiter: session_limiter.clone(),
+                        connection_games: session_games.clone(),
+                    });
+                    let mut request = listener.challenged_request();
+                    request.get().set_from(game_config.user.into());
+                    pry!(request.get().set_game_config(config_reader));
+                    request.get().set_challenge(challenge);
+                    Promise::from_future(async move {
+                        request.send().promise.await?;
+                        Ok(())
+                    })
+                },
+            );
+            drop(answer);
+
+            let rejection = match tokio::time::timeout(CHALLENGE_TIMEOUT, answered).await {
+                Ok(Ok(true)) => None,
+                Ok(Ok(false)) => Some(format!("{challenged} declined the challenge")),
+                // Every session of the challenged user dropped the challenge without answering
+                Ok(Err(_)) => Some(format!("{challenged} left without answering")),
+                Err(_) => Some(format!("{challenged} did not answer in time")),
+            };
+            if let Some(rejection) = rejection {
+                games.borrow_mut().games.remove(&id);
+                connection_games.borrow_mut().remove(&id);
+                return Err(Error::failed(rejection));
+            }
+            results
+                .get()
+                .set_game_side(capnp_rpc::new_client(GameSideImpl::new(
+                    id,
+                    Color::White,
+                    game_config.user.to_string(),
+                    db,
+                    games,
+                    word_filter,
+                    limiter,
+                )));
+            Ok(())
         })
     }
 
-    fn resume_game(&mut self, _: ResumeGameParams, _: ResumeGameResults) -> Promise<(), Error> {
-        todo!()
+    fn profile(&mut self, _: ProfileParams, mut results: ProfileResults) -> Promise<(), Error> {
+        pry!(self.limiter.check(None));
+        results
+            .get()
+            .set_profile(capnp_rpc::new_client(ProfileImpl {
+                db: self.db.clone(),
+                limiter: self.limiter.clone(),
+            }));
+        Promise::ok(())
+    }
+
+    fn directory(
+        &mut self,
+        _: DirectoryParams,
+        mut results: DirectoryResults,
+    ) -> Promise<(), Error> {
+        pry!(self.limiter.check(None));
+        results
+            .get()
+            .set_directory(capnp_rpc::new_client(GameDirectoryImpl {
+                games: self.games.clone(),
+                limiter: self.limiter.clone(),
+            }));
+        Promise::ok(())
+    }
+}
+
+/// A game offered to a user, in one of their sessions. The answer is shared by all of them until
+/// one answers.
+struct ChallengeImpl<C: Connection> {
+    id: u64,
+    challenged: String,
+    /// Tells the challenger whether the game was accepted, `None` once answered
+    answer: Rc<RefCell<Option<oneshot::Sender<bool>>>>,
+    db: Surreal<C>,
+    games: Rc<RefCell<Games>>,
+    word_filter: Rc<dyn WordFilter>,
+    /// Limiter of the connection of the session, for the challenge and the game side it gives
+    limiter: Limiter,
+    /// Games of the connection of the session, that the game joins once accepted
+    connection_games: ConnectionGames,
+}
+
+impl<C: Connection> ChallengeImpl<C> {
+    fn take_answer(&mut self) -> Result<oneshot::Sender<bool>, Error> {
+        let answer = self
+            .answer
+            .borrow_mut()
+            .take()
+            .ok_or_else(|| Error::failed("The challenge was already answered".to_string()))?;
+        // The challenger stops waiting once the challenge expires
+        if answer.is_canceled() {
+            return Err(Error::failed("The challenge has expired".to_string()));
+        }
+        Ok(answer)
+    }
+}
+
+impl<C: Connection> challenge::Server for ChallengeImpl<C> {
+    fn accept(&mut self, params: AcceptParams, mut results: AcceptResults) -> Promise<(), Error> {
+        pry!(self.limiter.check(Some(&self.challenged)));
+        pry!(self
+            .limiter
+            .check_open_games(self.games.borrow().open_games(&self.challenged)));
+        let player = pry!(pry!(params.get()).get_player());
+        let answer = pry!(self.take_answer());
+        let Some(color) = self
+            .games
+            .borrow_mut()
+            .join(self.id, player, &self.challenged)
+        else {
+            return Promise::err(Error::failed("The challenge has expired".to_string()));
+        };
+        let _ = answer.send(true);
+        self.connection_games.borrow_mut().insert(self.id, color);
+        results
+            .get()
+            .set_game_side(capnp_rpc::new_client(GameSideImpl::new(
+                self.id,
+                color,
+                self.challenged.clone(),
+                self.db.clone(),
+                self.games.clone(),
+                self.word_filter.clone(),
+                self.limiter.clone(),
+            )));
+        Promise::ok(())
+    }
+
+    fn decline(&mut self, _: DeclineParams, _: DeclineResults) -> Promise<(), Error> {
+        pry!(self.limiter.check(Some(&self.challenged)));
+        let _ = pry!(self.take_answer()).send(false);
+        Promise::ok(())
+    }
+}
+
+/// Hands the game maker to the clients that speak the protocol of the server.
+struct HandshakeImpl {
+    game_maker: game_maker::Client,
+}
+
+impl handshake::Server for HandshakeImpl {
+    fn hello(&mut self, params: HelloParams, mut results: HelloResults) -> Promise<(), Error> {
+        let version = pry!(params.get()).get_version();
+        if version != PROTOCOL_VERSION {
+            let outdated = if version < PROTOCOL_VERSION {
+                "client"
+            } else {
+                "server"
+            };
+            return Promise::err(Error::failed(format!(
+                "The client speaks version {version} of the protocol but the server speaks \
+                version {PROTOCOL_VERSION}, update the {outdated}"
+            )));
+        }
+        results.get().set_game_maker(self.game_maker.clone());
+        Promise::ok(())
+    }
+}
+
+/// Serves the handshake as the capability each connection starts with. Clients from before the
+/// handshake took the game maker from there, they are told to update instead of getting a call
+/// to an unimplemented interface.
+struct Bootstrap(handshake::ServerDispatch<HandshakeImpl>);
+
+impl capnp::capability::Server for Bootstrap {
+    fn dispatch_call(
+        &mut self,
+        interface_id: u64,
+        method_id: u16,
+        params: capnp::capability::Params<any_pointer::Owned>,
+        results: capnp::capability::Results<any_pointer::Owned>,
+    ) -> Promise<(), Error> {
+        if interface_id == <game_maker::Client as HasTypeId>::TYPE_ID {
+            return Promise::err(Error::failed(
+                "protocol version mismatch, update the client".to_string(),
+            ));
+        }
+        capnp::capability::Server::dispatch_call(
+            &mut self.0,
+            interface_id,
+            method_id,
+            params,
+            results,
+        )
+    }
+}
+
+impl Deref for Bootstrap {
+    type Target = HandshakeImpl;
+
+    fn deref(&self) -> &HandshakeImpl {
+        &self.0
+    }
+}
+
+impl DerefMut for Bootstrap {
+    fn deref_mut(&mut self) -> &mut HandshakeImpl {
+        &mut self.0
+    }
+}
+
+impl capnp::capability::FromServer<HandshakeImpl> for capnp::capability::Client {
+    type Dispatch = Bootstrap;
+
+    fn from_server(server: HandshakeImpl) -> Bootstrap {
+        Bootstrap(<handshake::Client as capnp::capability::FromServer<_>>::from_server(server))
+    }
+}
+
+/// Lists the games being played, and lets anyone watch them.
+struct GameDirectoryImpl {
+    games: Rc<RefCell<Games>>,
+    limiter: Limiter,
+}
+
+impl game_directory::Server for GameDirectoryImpl {
+    fn games(&mut self, _: GamesParams, mut results: GamesResults) -> Promise<(), Error> {
+        pry!(self.limiter.check(None));
+        let games = self.games.borrow();
+        let mut started: Vec<(&u64, &Game)> = games
+            .games
+            .iter()
+            .filter(|(_, game)| game.players.iter().all(Option::is_some) && game.result.is_none())
+            .collect();
+        started.sort_by_key(|(id, _)| **id);
+
+        let mut list = results.get().init_games(started.len() as u32);
+        for (i, (id, game)) in started.into_iter().enumerate() {
+            let mut info = list.reborrow().get(i as u32);
+            info.set_id(*id);
+            let [white, black] = &game.usernames;
+            info.set_white(white.as_deref().unwrap_or_default().into());
+            info.set_black(black.as_deref().unwrap_or_default().into());
+            info.set_ply(game.ply());
+        }
+        Promise::ok(())
+    }
+
+    fn watch(&mut self, params: WatchParams, mut results: WatchResults) -> Promise<(), Error> {
+        pry!(self.limiter.check(None));
+        let params = pry!(params.get());
+        let spectator = pry!(params.get_spectator());
+        let mut games = self.games.borrow_mut();
+        let game = pry!(games.get_mut(params.get_id()));
+        game.add_spectator(spectator);
+
+        let mut moves = results.get().init_moves(game.ply());
+        for (i, game_move) in game.moves.iter().enumerate() {
+            game_move.to_builder(moves.reborrow().get(i as u32));
+        }
+        Promise::ok(())
     }
 }
 
 struct GameSideImpl<C: Connection> {
     id: u64,
+    color: Color,
+    /// Name of the player on this side
+    username: String,
     db: Surreal<C>,
+    games: Rc<RefCell<Games>>,
+    word_filter: Rc<dyn WordFilter>,
+    chat_limiter: ChatLimiter,
+    limiter: Limiter,
 }
 
 impl<C: Connection> GameSideImpl<C> {
-    fn new(id: u64, db: Surreal<C>) -> Self {
-        GameSideImpl { id, db }
+    fn new(
+        id: u64,
+        color: Color,
+        username: String,
+        db: Surreal<C>,
+        games: Rc<RefCell<Games>>,
+        word_filter: Rc<dyn WordFilter>,
+        limiter: Limiter,
+    ) -> Self {
+        GameSideImpl {
+            id,
+            color,
+            username,
+            db,
+            games,
+            word_filter,
+            chat_limiter: ChatLimiter::default(),
+            limiter,
+        }
+    }
+
+    fn check_rate(&self) -> Result<(), Error> {
+        self.limiter.check(Some(&self.username))
     }
 }
 
 impl<C: Connection> game_side::Server for GameSideImpl<C> {
-    fn id(&mut self, _: IdParams, _: IdResults) -> Promise<(), Error> {
-        todo!()
+    fn id(&mut self, _: IdParams, mut results: IdResults) -> Promise<(), Error> {
+        results.get().set_id(self.id);
+        Promise::ok(())
     }
 
-    fn color(&mut self, _: ColorParams, _: ColorResults) -> Promise<(), Error> {
-        todo!()
+    fn color(&mut self, _: ColorParams, mut results: ColorResults) -> Promise<(), Error> {
+        results.get().set_color(self.color);
+        Promise::ok(())
     }
 
-    fn move_(&mut self, _: MoveParams, _: MoveResults) -> Promise<(), Error> {
-        todo!()
+    fn move_(&mut self, params: MoveParams, _: MoveResults) -> Promise<(), Error> {
+        pry!(self.check_rate());
+        let (id, color) = (self.id, self.color);
+        let db = self.db.clone();
+        let games = self.games.clone();
+        Promise::from_future(async move {
+            let proposed = ProposedMove::from_reader(params.get()?.get_move()?)?;
+            let (game_move, opponent, ply, result) = {
+                let mut games = games.borrow_mut();
+                let game = games.get_mut(id)?;
+                game.check_playing()?;
+                if game.turn() != color {
+                    return Err(Error::failed("It is not your turn to move".to_string()));
+                }
+                let opponent = game.opponent(color)?;
+                let mut board = position_after(&game.moves);
+                let game_move = proposed.check(&board, color, game.ply())?;
+                game_move.play(&mut board);
+                game.moves.push(game_move);
+                // Moving instead of answering declines the draw offer
+                if game.draw_offer == Some(opposite(color)) {
+                    game.draw_offer = None;
+                }
+                // The game ends when the opponent is left without a legal move
+                let result = board
+                    .ending(opposite(color))
+                    .map(|ending| GameResult::ending(ending, color));
+                if let Some(result) = result {
+                    game.end(result);
+                }
+                (game_move, opponent, game.ply(), result)
+            };
+
+            db.query("CREATE move CONTENT $move")
+                .bind((
+                    "move",
+                    SavedMove {
+                        game: id,
+                        ply,
+                        from: game_move.from,
+                        to: game_move.to,
+                        promotion: game_move.promotion.map(|piece_type| piece_type as u16),
+                    },
+                ))
+                .await
+                .map_err(db_error)?;
+
+            notify_spectators(&games, id, |spectator| {
+                let mut request = spectator.move_request();
+                game_move.to_builder(request.get().init_move());
+                Promise::from_future(async move {
+                    request.send().promise.await?;
+                    Ok(())
+                })
+            })?;
+
+            let mut request = opponent.move_request();
+            game_move.to_builder(request.get().init_move());
+            request.send().promise.await?;
+
+            if let Some(result) = result {
+                let player = games.borrow_mut().get_mut(id)?.players[color as usize].clone();
+                let notified = player.into_iter().chain([opponent]).collect();
+                save_result(&db, &games, id, ply, result, notified).await?;
+            }
+            Ok(())
+        })
+    }
+
+    fn request_takeback(
+        &mut self,
+        _: RequestTakebackParams,
+        mut results: RequestTakebackResults,
+    ) -> Promise<(), Error> {
+        pry!(self.check_rate());
+        let (id, color) = (self.id, self.color);
+        let db = self.db.clone();
+        let games = self.games.clone();
+        Promise::from_future(async move {
+            let (opponent, ply) = {
+                let mut games = games.borrow_mut();
+                let game = games.get_mut(id)?;
+                game.check_playing()?;
+                if game.moves.is_empty() {
+                    return Err(Error::failed("There is no move to take back".to_string()));
+                }
+                // The last move was played by the other side when it is this one's turn
+                if game.turn() == color {
+                    return Err(Error::failed(
+                        "Only your own last move can be taken back".to_string(),
+                    ));
+                }
+                (game.opponent(color)?, game.ply())
+            };
+            let accepted = opponent
+                .takeback_requested_request()
+                .send()
+                .promise
+                .await?
+                .get()?
+                .get_accept();
+
+            if accepted {
+                {
+                    let mut games = games.borrow_mut();
+                    let game = games.get_mut(id)?;
+                    // The opponent may have moved or the game ended while they were asked
+                    if game.ply() != ply || game.result.is_some() {
+                        return Err(Error::failed(
+                            "The game went on while the takeback was asked, it can't be taken \
+                            back anymore"
+                                .to_string(),
+                        ));
+                    }
+                    game.moves.pop();
+                }
+                db.query("DELETE move WHERE game=$game AND ply=$ply")
+                    .bind(("game", id))
+                    .bind(("ply", ply))
+                    .await
+                    .map_err(db_error)?;
+                notify_spectators(&games, id, |spectator| {
+                    let request = spectator.takeback_request();
+                    Promise::from_future(async move {
+                        request.send().promise.await?;
+                        Ok(())
+                    })
+                })?;
+            }
+            results.get().set_accepted(accepted);
+            Ok(())
+        })
+    }
+
+    fn chat(&mut self, params: ChatParams, _: ChatResults) -> Promise<(), Error> {
+        pry!(self.check_rate());
+        let message = pry!(pry!(pry!(params.get()).get_message()).to_str());
+        let message = pry!(chat::check_length(message).map_err(Error::failed));
+        pry!(self
+            .chat_limiter
+            .check(Instant::now())
+            .map_err(Error::failed));
+        let message = pry!(self.word_filter.filter(message).map_err(Error::failed));
+
+        let (id, color) = (self.id, self.color);
+        let username = self.username.clone();
+        let db = self.db.clone();
+        let games = self.games.clone();
+        Promise::from_future(async move {
+            let (opponent, ply) = {
+                let mut games = games.borrow_mut();
+                let game = games.get_mut(id)?;
+                (game.opponent(color)?, game.ply())
+            };
+            let sent_at = now();
+            let mut request = opponent.chat_message_request();
+            request.get().set_from(username.as_str().into());
+            request.get().set_message(message.as_str().into());
+            db.query("CREATE chat CONTENT $message")
+                .bind((
+                    "message",
+                    SavedChat {
+                        game: id,
+                        ply,
+                        username,
+                        message,
+                        sent_at,
+                    },
+                ))
+                .await
+                .map_err(db_error)?;
+
+            request.send().promise.await?;
+            Ok(())
+        })
+    }
+
+    fn offer_draw(&mut self, _: OfferDrawParams, _: OfferDrawResults) -> Promise<(), Error> {
+        pry!(self.check_rate());
+        let color = self.color;
+        let opponent = {
+            let mut games = self.games.borrow_mut();
+            let game = pry!(games.get_mut(self.id));
+            pry!(game.check_playing());
+            if game.draw_offer.is_some() {
+                return Promise::err(Error::failed(
+                    "A draw offer is already waiting for an answer".to_string(),
+                ));
+            }
+            let opponent = pry!(game.opponent(color));
+            game.draw_offer = Some(color);
+            opponent
+        };
+        Promise::from_future(async move {
+            opponent.draw_offered_request().send().promise.await?;
+            Ok(())
+        })
+    }
+
+    fn accept_draw(
+        &mut self,
+        _: AcceptDrawParams,
+        mut results: AcceptDrawResults,
+    ) -> Promise<(), Error> {
+        pry!(self.check_rate());
+        let result = GameResult::draw_agreed();
+        let (opponent, ply) = {
+            let mut games = self.games.borrow_mut();
+            let game = pry!(games.get_mut(self.id));
+            pry!(game.check_draw_offered_to(self.color));
+            let opponent = pry!(game.opponent(self.color));
+            game.end(result);
+            (opponent, game.ply())
+        };
+        result.write(results.get().init_result());
+
+        let id = self.id;
+        let db = self.db.clone();
+        let games = self.games.clone();
+        Promise::from_future(async move {
+            save_result(&db, &games, id, ply, result, vec![opponent]).await
+        })
+    }
+
+    fn decline_draw(&mut self, _: DeclineDrawParams, _: DeclineDrawResults) -> Promise<(), Error> {
+        pry!(self.check_rate());
+        let opponent = {
+            let mut games = self.games.borrow_mut();
+            let game = pry!(games.get_mut(self.id));
+            pry!(game.check_draw_offered_to(self.color));
+            game.draw_offer = None;
+            pry!(game.opponent(self.color))
+        };
+        Promise::from_future(async move {
+            opponent.draw_declined_request().send().promise.await?;
+            Ok(())
+        })
+    }
+
+    fn resign(&mut self, _: ResignParams, mut results: ResignResults) -> Promise<(), Error> {
+        pry!(self.check_rate());
+        let result = GameResult::resignation(self.color);
+        let (opponent, ply) = {
+            let mut games = self.games.borrow_mut();
+            let game = pry!(games.get_mut(self.id));
+            pry!(game.check_playing());
+            let opponent = pry!(game.opponent(self.color));
+            game.end(result);
+            (opponent, game.ply())
+        };
+        result.write(results.get().init_result());
+
+        let id = self.id;
+        let db = self.db.clone();
+        let games = self.games.clone();
+        Promise::from_future(async move {
+            save_result(&db, &games, id, ply, result, vec![opponent]).await
+        })
+    }
+
+    fn abort(&mut self, _: AbortParams, mut results: AbortResults) -> Promise<(), Error> {
+        pry!(self.check_rate());
+        let result = GameResult::aborted();
+        let (opponent, ply) = {
+            let mut games = self.games.borrow_mut();
+            let game = pry!(games.get_mut(self.id));
+            pry!(game.check_playing());
+            if game.ply() >= 2 {
+                return Promise::err(Error::failed(
+                    "The game can only be aborted until both players have made their first move"
+                        .to_string(),
+                ));
+            }
+            // The game may still be waiting for an opponent
+            let opponent = game.players[opposite(self.color) as usize].clone();
+            game.end(result);
+            (opponent, game.ply())
+        };
+        result.write(results.get().init_result());
+
+        let id = self.id;
+        let db = self.db.clone();
+        let games = self.games.clone();
+        Promise::from_future(async move {
+            db.query("DELETE lobby WHERE game=$game")
+                .bind(("game", id))
+                .await
+                .map_err(db_error)?;
+            save_result(&db, &games, id, ply, result, opponent.into_iter().collect()).await
+        })
     }
 }
 
-/// Simple program to greet a person
+/// Server of Fluffy Chess games, for the clients connecting over TCP or WebSocket. Logs to stderr
+/// at the level set by RUST_LOG, info by default.
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// Name of the person to greet
+    /// Address and port to listen on for TCP connections
     #[arg(value_parser = parse_socket_addr, default_value = "localhost:7171")]
     address: SocketAddr,
+    /// Address and port to also listen on for WebSocket connections, from browsers
+    #[arg(long, value_parser = parse_socket_addr)]
+    websocket: Option<SocketAddr>,
+    /// File of words masked in chat messages, one per line
+    #[arg(long)]
+    banned_words: Option<PathBuf>,
+    /// PEM file of the certificate chain to encrypt the connections with TLS, on both listeners
+    #[arg(long, requires = "private_key")]
+    certificate: Option<PathBuf>,
+    /// PEM file of the private key of the certificate
+    #[arg(long, requires = "certificate")]
+    private_key: Option<PathBuf>,
 }
 
 fn parse_socket_addr(s: &str) -> Result<SocketAddr, String> {
@@ -145,9 +1344,169 @@ fn parse_socket_addr(s: &str) -> Result<SocketAddr, String> {
         .ok_or_else(|| "No address parsed".to_string())
 }
 
+/// Serve the protocol on a connection, starting with the handshake, until the client leaves,
+/// sends a message that can't be read or stays idle for too long.
+fn serve<C: Connection>(
+    stream: impl futures::AsyncRead + futures::AsyncWrite + 'static,
+    game_maker: &GameMakerImpl<C>,
+) {
+    let game_maker = game_maker.for_connection();
+    let limiter = game_maker.limiter.clone();
+    let (db, games) = (game_maker.db.clone(), game_maker.games.clone());
+    let connection_games = game_maker.connection_games.clone();
+    let bootstrap: capnp::capability::Client = capnp_rpc::new_client(HandshakeImpl {
+        game_maker: capnp_rpc::new_client(game_maker),
+    });
+
+    let last_active = Rc::new(Cell::new(Instant::now()));
+    let (reader, writer) = stream.split();
+    let mut options = capnp::message::ReaderOptions::new();
+    options.traversal_limit_in_words(Some(limits::MAX_MESSAGE_WORDS));
+    let network = twoparty::VatNetwork::new(
+        Tracked::new(reader, last_active.clone()),
+        Tracked::new(writer, last_active.clone()),
+        rpc_twoparty_capnp::Side::Server,
+        options,
+    );
+    let rpc_system = RpcSystem::new(Box::new(network), Some(bootstrap));
+    tokio::task::spawn_local(async move {
+        // Dropping the RPC system closes the connection
+        tokio::select! {
+            served = rpc_system => {
+                if let Err(err) = served {
+                    limiter.count_unreadable();
+                    warn!("Closed a connection: {err}");
+                }
+            }
+            _ = limiter.idle(last_active) => {}
+        }
+        // The opponents can't finish the games left with the connection
+        let left: Vec<(u64, Color)> = connection_games.borrow_mut().drain().collect();
+        for (id, color) in left {
+            if let Err(err) = leave_game(&db, &games, id, color).await {
+                error!("Failed to end game {id} left by its player: {err}");
+            }
+        }
+    });
+}
+
+/// End a game whose `color` player left. A game waiting for an opponent is removed with its lobby
+/// entry or challenge, a game where both players haven't moved yet is aborted, and the player who
+/// left loses the other games.
+async fn leave_game<C: Connection>(
+    db: &Surreal<C>,
+    games: &Rc<RefCell<Games>>,
+    id: u64,
+    color: Color,
+) -> Result<(), Error> {
+    let ended = {
+        let mut games = games.borrow_mut();
+        let Ok(game) = games.get_mut(id) else {
+            return Ok(());
+        };
+        if game.result.is_some() {
+            return Ok(());
+        }
+        match game.players[opposite(color) as usize].clone() {
+            None => {
+                games.games.remove(&id);
+                None
+            }
+            Some(opponent) => {
+                let result = if game.ply() < 2 {
+                    GameResult::aborted()
+                } else {
+                    GameResult::abandoned(color)
+                };
+                game.end(result);
+                Some((opponent, game.ply(), result))
+            }
+        }
+    };
+    match ended {
+        Some((opponent, ply, result)) => {
+            save_result(db, games, id, ply, result, vec![opponent]).await
+        }
+        None => {
+            db.query("DELETE lobby WHERE game=$game")
+                .bind(("game", id))
+                .await
+                .map_err(db_error)?;
+            Ok(())
+        }
+    }
+}
+
+async fn listen_tcp<C: Connection>(
+    address: SocketAddr,
+    tls: Option<TlsAcceptor>,
+    game_maker: Rc<GameMakerImpl<C>>,
+) -> io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(address).await?;
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        stream.set_nodelay(true)?;
+        let (tls, game_maker) = (tls.clone(), game_maker.clone());
+        // A slow TLS handshake must not hold up the next connections
+        tokio::task::spawn_local(async move {
+            match game_maker.limiter.handshake(tls::secure(stream, tls)).await {
+                Some(Ok(stream)) => serve(
+                    tokio_util::compat::TokioAsyncReadCompatExt::compat(stream),
+                    &game_maker,
+                ),
+                Some(Err(err)) => info!("TLS handshake with {peer} failed: {err}"),
+                None => info!("TLS handshake with {peer} timed out"),
+            }
+        });
+    }
+}
+
+/// Accept connections carrying the protocol in the binary messages of a WebSocket.
+async fn listen_websocket<C: Connection>(
+    address: SocketAddr,
+    tls: Option<TlsAcceptor>,
+    game_maker: Rc<GameMakerImpl<C>>,
+) -> io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(address).await?;
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        stream.set_nodelay(true)?;
+        let (tls, game_maker) = (tls.clone(), game_maker.clone());
+        // Slow handshakes must not hold up the next connections
+        tokio::task::spawn_local(async move {
+            let limiter = &game_maker.limiter;
+            let stream = match limiter.handshake(tls::secure(stream, tls)).await {
+                Some(Ok(stream)) => stream,
+                Some(Err(err)) => {
+                    info!("TLS handshake with {peer} failed: {err}");
+                    return;
+                }
+                None => {
+                    info!("TLS handshake with {peer} timed out");
+                    return;
+                }
+            };
+            match limiter
+                .handshake(tokio_tungstenite::accept_async(stream))
+                .await
+            {
+                Some(Ok(websocket)) => serve(WebSocketIo::new(websocket), &game_maker),
+                Some(Err(err)) => info!("WebSocket handshake with {peer} failed: {err}"),
+                None => info!("WebSocket handshake with {peer} timed out"),
+            }
+        });
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .with_writer(io::stderr)
+        .init();
 
     tokio::task::LocalSet::new()
         .run_until(async move {
@@ -157,28 +1516,33 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             // Select a specific namespace / database
             db.use_ns("fluffy_chess").use_db("main").await?;
 
-            let game_maker_impl = GameMakerImpl::new(db.clone());
-            let game_maker: game_maker::Client = capnp_rpc::new_client(game_maker_impl);
-
-            let listener = tokio::net::TcpListener::bind(&args.address).await?;
-            let handle_incoming = async move {
-                loop {
-                    let (stream, _) = listener.accept().await?;
-                    stream.set_nodelay(true)?;
-                    let (reader, writer) =
-                        tokio_util::compat::TokioAsyncReadCompatExt::compat(stream).split();
-                    let network = twoparty::VatNetwork::new(
-                        reader,
-                        writer,
-                        rpc_twoparty_capnp::Side::Server,
-                        Default::default(),
-                    );
-                    let rpc_system =
-                        RpcSystem::new(Box::new(network), Some(game_maker.clone().client));
-
-                    tokio::task::spawn_local(rpc_system);
+            let word_filter: Rc<dyn WordFilter> = match &args.banned_words {
+                Some(path) => Rc::new(BannedWords::load(path)?),
+                None => Rc::new(NoFilter),
+            };
+            let metrics = Rc::new(Metrics::default());
+            tokio::task::spawn_local(metrics.clone().report());
+            // Every connection gets a copy of the game maker, sharing everything but its limiter
+            let game_maker = Rc::new(GameMakerImpl::new(db.clone(), word_filter, metrics));
+
+            let tls = match (&args.certificate, &args.private_key) {
+                (Some(certificate), Some(private_key)) => {
+                    Some(tls::load_acceptor(certificate, private_key)?)
+                }
+                _ => None,
+            };
+            let websocket = async {
+                match args.websocket {
+                    Some(address) => {
+                        listen_websocket(address, tls.clone(), game_maker.clone()).await
+                    }
+                    None => future::pending().await,
                 }
             };
+            futures::try_join!(
+                listen_tcp(args.address, tls.clone(), game_maker.clone()),
+                websocket
+            )?;
             Ok(())
         })
         .await
@@ -0,0 +1,218 @@
+use crate::db_error;
+use crate::limits::Limiter;
+use crate::now;
+use crate::rating::{Rating, TimeControl};
+use capnp::capability::Promise;
+use capnp::Error;
+use capnp_rpc::pry;
+use fluffy_chess_protocol::fluffy_chess_capnp::{profile, TimeControl as WireTimeControl};
+use serde::{Deserialize, Serialize};
+use surrealdb::{Connection, Surreal};
+
+/// Current rating of a player at a time control, as it is saved in the database.
+#[derive(Deserialize, Serialize)]
+struct SavedRating {
+    username: String,
+    time_control: TimeControl,
+    rating: Rating,
+    /// Number of rated games played
+    games: u32,
+}
+
+/// Rating of a player after a game, as it is saved in the database.
+#[derive(Deserialize, Serialize)]
+struct SavedRatingChange {
+    username: String,
+    time_control: TimeControl,
+    game: u64,
+    rating: Rating,
+    /// Seconds since the Unix epoch
+    recorded_at: u64,
+}
+
+async fn load_saved<C: Connection>(
+    db: &Surreal<C>,
+    username: &str,
+    time_control: TimeControl,
+) -> Result<Option<SavedRating>, Error> {
+    db.query("SELECT * FROM rating WHERE username=$username AND time_control=$time_control")
+        .bind(("username", username))
+        .bind(("time_control", time_control))
+        .await
+        .and_then(|mut response| response.take(0))
+        .map_err(db_error)
+}
+
+/// Rating of a player at a time control, the initial one if they never played a rated game.
+pub async fn load_rating<C: Connection>(
+    db: &Surreal<C>,
+    username: &str,
+    time_control: TimeControl,
+) -> Result<Rating, Error> {
+    Ok(load_saved(db, username, time_control)
+        .await?
+        .map(|saved| saved.rating)
+        .unwrap_or_default())
+}
+
+/// Rate a finished game between `usernames`, white first, where white scored `white_score`.
+pub async fn update_ratings<C: Connection>(
+    db: &Surreal<C>,
+    game: u64,
+    usernames: [&str; 2],
+    time_control: TimeControl,
+    white_score: f64,
+) -> Result<(), Error> {
+    let [white, black] = usernames;
+    let white_saved = load_saved(db, white, time_control).await?;
+    let black_saved = load_saved(db, black, time_control).await?;
+    let white_rating = white_saved
+        .as_ref()
+        .map(|saved| saved.rating)
+        .unwrap_or_default();
+    let black_rating = black_saved
+        .as_ref()
+        .map(|saved| saved.rating)
+        .unwrap_or_default();
+
+    // Each game is a rating period of its own
+    let updated = [
+        (
+            white,
+            white_saved,
+            white_rating.update(&[(black_rating, white_score)]),
+        ),
+        (
+            black,
+            black_saved,
+            black_rating.update(&[(white_rating, 1. - white_score)]),
+        ),
+    ];
+    let recorded_at = now();
+    for (username, saved, rating) in updated {
+        let games = saved.map_or(0, |saved| saved.games) + 1;
+        db.query("DELETE rating WHERE username=$username AND time_control=$time_control")
+            .query("CREATE rating CONTENT $rating")
+            .query("CREATE rating_change CONTENT $change")
+            .bind(("username", username))
+            .bind(("time_control", time_control))
+            .bind((
+                "rating",
+                SavedRating {
+                    username: username.to_string(),
+                    time_control,
+                    rating,
+                    games,
+                },
+            ))
+            .bind((
+                "change",
+                SavedRatingChange {
+                    username: username.to_string(),
+                    time_control,
+                    game,
+                    rating,
+                    recorded_at,
+                },
+            ))
+            .await
+            .map_err(db_error)?;
+    }
+    Ok(())
+}
+
+impl From<TimeControl> for WireTimeControl {
+    fn from(value: TimeControl) -> Self {
+        match value {
+            TimeControl::Unlimited => WireTimeControl::Unlimited,
+            TimeControl::PerTurn => WireTimeControl::PerTurn,
+            TimeControl::Bullet => WireTimeControl::Bullet,
+            TimeControl::Blitz => WireTimeControl::Blitz,
+            TimeControl::Rapid => WireTimeControl::Rapid,
+            TimeControl::Classical => WireTimeControl::Classical,
+        }
+    }
+}
+
+impl From<WireTimeControl> for TimeControl {
+    fn from(value: WireTimeControl) -> Self {
+        match value {
+            WireTimeControl::Unlimited => TimeControl::Unlimited,
+            WireTimeControl::PerTurn => TimeControl::PerTurn,
+            WireTimeControl::Bullet => TimeControl::Bullet,
+            WireTimeControl::Blitz => TimeControl::Blitz,
+            WireTimeControl::Rapid => TimeControl::Rapid,
+            WireTimeControl::Classical => TimeControl::Classical,
+        }
+    }
+}
+
+/// Answers questions about the ratings of the players.
+pub struct ProfileImpl<C: Connection> {
+    pub db: Surreal<C>,
+    pub limiter: Limiter,
+}
+
+impl<C: Connection> profile::Server for ProfileImpl<C> {
+    fn ratings(
+        &mut self,
+        params: profile::RatingsParams,
+        mut results: profile::RatingsResults,
+    ) -> Promise<(), Error> {
+        pry!(self.limiter.check(None));
+        let db = self.db.clone();
+        Promise::from_future(async move {
+            let username = params.get()?.get_username()?.to_str()?;
+            let ratings: Vec<SavedRating> = db
+                .query("SELECT * FROM rating WHERE username=$username")
+                .bind(("username", username))
+                .await
+                .and_then(|mut response| response.take(0))
+                .map_err(db_error)?;
+
+            let mut list = results.get().init_ratings(ratings.len() as u32);
+            for (i, saved) in ratings.iter().enumerate() {
+                let mut rating = list.reborrow().get(i as u32);
+                rating.set_time_control(saved.time_control.into());
+                rating.set_rating(saved.rating.rating);
+                rating.set_deviation(saved.rating.deviation);
+                rating.set_games(saved.games);
+            }
+            Ok(())
+        })
+    }
+
+    fn rating_history(
+        &mut self,
+        params: profile::RatingHistoryParams,
+        mut results: profile::RatingHistoryResults,
+    ) -> Promise<(), Error> {
+        pry!(self.limiter.check(None));
+        let db = self.db.clone();
+        Promise::from_future(async move {
+            let params = params.get()?;
+            let username = params.get_username()?.to_str()?;
+            let time_control: TimeControl = params.get_time_control()?.into();
+            let changes: Vec<SavedRatingChange> = db
+                .query(
+                    "SELECT * FROM rating_change \
+                    WHERE username=$username AND time_control=$time_control ORDER BY recorded_at",
+                )
+                .bind(("username", username))
+                .bind(("time_control", time_control))
+                .await
+                .and_then(|mut response| response.take(0))
+                .map_err(db_error)?;
+
+            let mut list = results.get().init_history(changes.len() as u32);
+            for (i, saved) in changes.iter().enumerate() {
+                let mut change = list.reborrow().get(i as u32);
+                change.set_game(saved.game);
+                change.set_rating(saved.rating.rating);
+                change.set_deviation(saved.rating.deviation);
+                change.set_recorded_at(saved.recorded_at);
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,205 @@
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use std::time::Duration;
+
+/// Glicko-2 values of a new player
+const INITIAL_RATING: f64 = 1500.;
+const INITIAL_DEVIATION: f64 = 350.;
+const INITIAL_VOLATILITY: f64 = 0.06;
+/// Constrains the change of volatility over time, between 0.3 and 1.2
+const TAU: f64 = 0.5;
+/// Ratio between the Glicko and the Glicko-2 scales
+const SCALE: f64 = 173.7178;
+/// Precision of the volatility
+const EPSILON: f64 = 0.000001;
+
+/// Rating difference accepted between players looking for a rated game
+const INITIAL_WINDOW: f64 = 100.;
+/// How much the rating window widens each second a player waits
+const WINDOW_GROWTH: f64 = 10.;
+const MAX_WINDOW: f64 = 600.;
+
+/// Pace of a game, each one has its own ratings.
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize, Serialize)]
+pub enum TimeControl {
+    Unlimited,
+    PerTurn,
+    /// Under 3 minutes per game
+    Bullet,
+    /// Under 10 minutes per game
+    Blitz,
+    /// Under 30 minutes per game
+    Rapid,
+    Classical,
+}
+
+impl TimeControl {
+    /// Time control of a game where each player has `seconds` for the whole game.
+    pub fn per_game(seconds: u32) -> Self {
+        match seconds {
+            0..=179 => TimeControl::Bullet,
+            180..=599 => TimeControl::Blitz,
+            600..=1799 => TimeControl::Rapid,
+            _ => TimeControl::Classical,
+        }
+    }
+}
+
+/// Glicko-2 rating of a player, on the Glicko scale.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct Rating {
+    pub rating: f64,
+    /// How uncertain the rating is, it shrinks as more games are played
+    pub deviation: f64,
+    /// How much the rating is expected to fluctuate
+    pub volatility: f64,
+}
+
+impl Default for Rating {
+    fn default() -> Self {
+        Self {
+            rating: INITIAL_RATING,
+            deviation: INITIAL_DEVIATION,
+            volatility: INITIAL_VOLATILITY,
+        }
+    }
+}
+
+fn g(phi: f64) -> f64 {
+    1. / (1. + 3. * phi * phi / (PI * PI)).sqrt()
+}
+
+/// Expected score against an opponent, in the Glicko-2 scale.
+fn expected_score(mu: f64, opponent_mu: f64, opponent_phi: f64) -> f64 {
+    1. / (1. + (-g(opponent_phi) * (mu - opponent_mu)).exp())
+}
+
+impl Rating {
+    /// The rating after a rating period with these games, each with the rating of the opponent
+    /// before the period and the score: 1 for a win, 0.5 for a draw and 0 for a loss.
+    pub fn update(&self, games: &[(Rating, f64)]) -> Rating {
+        let mu = (self.rating - INITIAL_RATING) / SCALE;
+        let phi = self.deviation / SCALE;
+        if games.is_empty() {
+            // Only the deviation grows without games
+            let phi = (phi * phi + self.volatility * self.volatility).sqrt();
+            return Rating {
+                deviation: (phi * SCALE).min(INITIAL_DEVIATION),
+                ..*self
+            };
+        }
+
+        let opponents: Vec<(f64, f64, f64)> = games
+            .iter()
+            .map(|(opponent, score)| {
+                let opponent_mu = (opponent.rating - INITIAL_RATING) / SCALE;
+                let opponent_phi = opponent.deviation / SCALE;
+                (
+                    g(opponent_phi),
+                    expected_score(mu, opponent_mu, opponent_phi),
+                    *score,
+                )
+            })
+            .collect();
+        let v = 1.
+            / opponents
+                .iter()
+                .map(|(g, e, _)| g * g * e * (1. - e))
+                .sum::<f64>();
+        let improvement: f64 = opponents.iter().map(|(g, e, s)| g * (s - e)).sum();
+        let delta = v * improvement;
+
+        // Find the new volatility with the Illinois algorithm
+        let a = (self.volatility * self.volatility).ln();
+        let f = |x: f64| {
+            let ex = x.exp();
+            let d = phi * phi + v + ex;
+            ex * (delta * delta - d + v) / (2. * d * d) - (x - a) / (TAU * TAU)
+        };
+        let mut big_a = a;
+        let mut big_b = if delta * delta > phi * phi + v {
+            (delta * delta - phi * phi - v).ln()
+        } else {
+            let mut k = 1.;
+            while f(a - k * TAU) < 0. {
+                k += 1.;
+            }
+            a - k * TAU
+        };
+        let mut f_a = f(big_a);
+        let mut f_b = f(big_b);
+        while (big_b - big_a).abs() > EPSILON {
+            let big_c = big_a + (big_a - big_b) * f_a / (f_b - f_a);
+            let f_c = f(big_c);
+            if f_c * f_b <= 0. {
+                big_a = big_b;
+                f_a = f_b;
+            } else {
+                f_a /= 2.;
+            }
+            big_b = big_c;
+            f_b = f_c;
+        }
+        let volatility = (big_a / 2.).exp();
+
+        let phi_star = (phi * phi + volatility * volatility).sqrt();
+        let new_phi = 1. / (1. / (phi_star * phi_star) + 1. / v).sqrt();
+        let new_mu = mu + new_phi * new_phi * improvement;
+        Rating {
+            rating: new_mu * SCALE + INITIAL_RATING,
+            deviation: (new_phi * SCALE).min(INITIAL_DEVIATION),
+            volatility,
+        }
+    }
+}
+
+/// Rating difference accepted with a player who has waited this long for a rated game.
+pub fn rating_window(waited: Duration) -> f64 {
+    (INITIAL_WINDOW + WINDOW_GROWTH * waited.as_secs_f64()).min(MAX_WINDOW)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rating(rating: f64, deviation: f64) -> Rating {
+        Rating {
+            rating,
+            deviation,
+            volatility: INITIAL_VOLATILITY,
+        }
+    }
+
+    /// The worked example of Glickman's "Example of the Glicko-2 system", which also uses τ = 0.5.
+    #[test]
+    fn glickman_example() {
+        let updated = rating(1500., 200.).update(&[
+            (rating(1400., 30.), 1.),
+            (rating(1550., 100.), 0.),
+            (rating(1700., 300.), 0.),
+        ]);
+        assert!((updated.rating - 1464.06).abs() < 0.01, "{updated:?}");
+        assert!((updated.deviation - 151.52).abs() < 0.01, "{updated:?}");
+        assert!(
+            (updated.volatility - 0.05999).abs() < 0.00001,
+            "{updated:?}"
+        );
+    }
+
+    #[test]
+    fn deviation_grows_without_games() {
+        let updated = rating(1500., 50.).update(&[]);
+        assert_eq!(updated.rating, 1500.);
+        assert!(updated.deviation > 50.);
+        assert_eq!(rating(1500., 350.).update(&[]).deviation, INITIAL_DEVIATION);
+    }
+
+    #[test]
+    fn window_grows_up_to_its_cap() {
+        assert_eq!(rating_window(Duration::ZERO), INITIAL_WINDOW);
+        assert_eq!(rating_window(Duration::from_secs(10)), 200.);
+        assert!(rating_window(Duration::from_secs(11)) > rating_window(Duration::from_secs(10)));
+        assert_eq!(rating_window(Duration::from_secs(50)), MAX_WINDOW);
+        assert_eq!(rating_window(Duration::from_secs(3600)), MAX_WINDOW);
+    }
+}
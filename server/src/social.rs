@@ -0,0 +1,345 @@
+use crate::limits::Limiter;
+use crate::{db_error, ConnectionGames};
+use capnp::capability::Promise;
+use capnp::Error;
+use capnp_rpc::pry;
+use fluffy_chess_protocol::fluffy_chess_capnp::{social, social_listener};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use surrealdb::{Connection, Surreal};
+
+/// Users connected to the server, with the listeners of each of their sessions and the limiter
+/// and the games of the connection they are open on.
+#[derive(Default)]
+pub struct Presence {
+    sessions: HashMap<String, HashMap<u64, (social_listener::Client, Limiter, ConnectionGames)>>,
+    next_session: u64,
+}
+
+impl Presence {
+    pub fn is_online(&self, username: &str) -> bool {
+        self.sessions.contains_key(username)
+    }
+
+    /// Open a session, returns its ID and whether the user just came online.
+    fn connect(
+        &mut self,
+        username: &str,
+        listener: social_listener::Client,
+        limiter: Limiter,
+        connection_games: ConnectionGames,
+    ) -> (u64, bool) {
+        let session = self.next_session;
+        self.next_session += 1;
+        let sessions = self.sessions.entry(username.to_string()).or_default();
+        sessions.insert(session, (listener, limiter, connection_games));
+        (session, sessions.len() == 1)
+    }
+
+    /// Close a session, returns whether the user went offline.
+    fn disconnect(&mut self, username: &str, session: u64) -> bool {
+        let Some(sessions) = self.sessions.get_mut(username) else {
+            return false;
+        };
+        sessions.remove(&session);
+        if sessions.is_empty() {
+            self.sessions.remove(username);
+            return true;
+        }
+        false
+    }
+}
+
+/// Send a notification to every session of a user, without waiting for it to be received.
+pub fn notify(
+    presence: &Rc<RefCell<Presence>>,
+    username: &str,
+    request: impl Fn(&social_listener::Client) -> Promise<(), Error>,
+) {
+    notify_sessions(presence, username, |listener, _, _| request(listener));
+}
+
+/// Send a notification to every session of a user, given the limiter and the games of the
+/// connection of the session, without waiting for it to be received.
+pub fn notify_sessions(
+    presence: &Rc<RefCell<Presence>>,
+    username: &str,
+    request: impl Fn(&social_listener::Client, &Limiter, &ConnectionGames) -> Promise<(), Error>,
+) {
+    let sessions: Vec<(social_listener::Client, Limiter, ConnectionGames)> = presence
+        .borrow()
+        .sessions
+        .get(username)
+        .map(|sessions| sessions.values().cloned().collect())
+        .unwrap_or_default();
+    for (listener, limiter, connection_games) in sessions {
+        let sent = request(&listener, &limiter, &connection_games);
+        // A session that can't be reached is closed when its connection drops
+        tokio::task::spawn_local(async move {
+            let _ = sent.await;
+        });
+    }
+}
+
+/// Usernames of the friends of a user.
+pub async fn friends_of<C: Connection>(
+    db: &Surreal<C>,
+    username: &str,
+) -> Result<Vec<String>, Error> {
+    db.query("SELECT VALUE meta::id(out) FROM friend WHERE in=type::thing('user', $user)")
+        .bind(("user", username))
+        .await
+        .and_then(|mut response| response.take(0))
+        .map_err(db_error)
+}
+
+/// Usernames of the users asking to become friends with a user.
+async fn requests_to<C: Connection>(db: &Surreal<C>, username: &str) -> Result<Vec<String>, Error> {
+    db.query("SELECT VALUE meta::id(in) FROM friend_request WHERE out=type::thing('user', $user)")
+        .bind(("user", username))
+        .await
+        .and_then(|mut response| response.take(0))
+        .map_err(db_error)
+}
+
+/// Make `user` and `other` friends, once `user` accepted the request of `other`.
+async fn befriend<C: Connection>(
+    db: &Surreal<C>,
+    presence: &Rc<RefCell<Presence>>,
+    user: &str,
+    other: &str,
+) -> Result<(), Error> {
+    // Friendship goes both ways, with an edge in each direction
+    db.query(
+        "LET $a = type::thing('user', $user); LET $b = type::thing('user', $other); \
+        DELETE friend_request WHERE (in=$a AND out=$b) OR (in=$b AND out=$a); \
+        RELATE $a->friend->$b; RELATE $b->friend->$a",
+    )
+    .bind(("user", user))
+    .bind(("other", other))
+    .await
+    .map_err(db_error)?;
+
+    let user = user.to_string();
+    notify(presence, other, |listener| {
+        let mut request = listener.friend_request_accepted_request();
+        request.get().set_username(user.as_str().into());
+        Promise::from_future(async move {
+            request.send().promise.await?;
+            Ok(())
+        })
+    });
+    Ok(())
+}
+
+/// Tell the friends of a user that they connected or left.
+fn announce<C: Connection>(
+    db: Surreal<C>,
+    presence: Rc<RefCell<Presence>>,
+    username: String,
+    online: bool,
+) {
+    tokio::task::spawn_local(async move {
+        let Ok(friends) = friends_of(&db, &username).await else {
+            return;
+        };
+        for friend in friends {
+            notify(&presence, &friend, |listener| {
+                let mut request = listener.presence_request();
+                request.get().set_username(username.as_str().into());
+                request.get().set_online(online);
+                Promise::from_future(async move {
+                    request.send().promise.await?;
+                    Ok(())
+                })
+            });
+        }
+    });
+}
+
+/// A session of a user, who is shown online to their friends while it is open.
+pub struct SocialImpl<C: Connection> {
+    db: Surreal<C>,
+    presence: Rc<RefCell<Presence>>,
+    username: String,
+    session: u64,
+    limiter: Limiter,
+}
+
+impl<C: Connection> SocialImpl<C> {
+    pub fn connect(
+        db: Surreal<C>,
+        presence: Rc<RefCell<Presence>>,
+        username: String,
+        listener: social_listener::Client,
+        limiter: Limiter,
+        connection_games: ConnectionGames,
+    ) -> Self {
+        let (session, came_online) =
+            presence
+                .borrow_mut()
+                .connect(&username, listener, limiter.clone(), connection_games);
+        if came_online {
+            announce(db.clone(), presence.clone(), username.clone(), true);
+        }
+        Self {
+            db,
+            presence,
+            username,
+            session,
+            limiter,
+        }
+    }
+}
+
+impl<C: Connection> Drop for SocialImpl<C> {
+    fn drop(&mut self) {
+        if self
+            .presence
+            .borrow_mut()
+            .disconnect(&self.username, self.session)
+        {
+            announce(
+                self.db.clone(),
+                self.presence.clone(),
+                self.username.clone(),
+                false,
+            );
+        }
+    }
+}
+
+impl<C: Connection> social::Server for SocialImpl<C> {
+    fn send_friend_request(
+        &mut self,
+        params: social::SendFriendRequestParams,
+        _: social::SendFriendRequestResults,
+    ) -> Promise<(), Error> {
+        pry!(self.limiter.check(Some(&self.username)));
+        let other = pry!(pry!(pry!(params.get()).get_username()).to_str()).to_string();
+        if other == self.username {
+            return Promise::err(Error::failed("You can't be your own friend".to_string()));
+        }
+        let db = self.db.clone();
+        let presence = self.presence.clone();
+        let user = self.username.clone();
+        Promise::from_future(async move {
+            if friends_of(&db, &user).await?.contains(&other) {
+                return Err(Error::failed(format!(
+                    "You are already friends with {other}"
+                )));
+            }
+            if requests_to(&db, &other).await?.contains(&user) {
+                return Err(Error::failed(format!(
+                    "You already asked {other} to become friends"
+                )));
+            }
+            // They asked first, so both want to be friends
+            if requests_to(&db, &user).await?.contains(&other) {
+                return befriend(&db, &presence, &user, &other).await;
+            }
+
+            db.query(
+                "LET $a = type::thing('user', $user); LET $b = type::thing('user', $other); \
+                RELATE $a->friend_request->$b",
+            )
+            .bind(("user", &user))
+            .bind(("other", &other))
+            .await
+            .map_err(db_error)?;
+            notify(&presence, &other, |listener| {
+                let mut request = listener.friend_request_request();
+                request.get().set_from(user.as_str().into());
+                Promise::from_future(async move {
+                    request.send().promise.await?;
+                    Ok(())
+                })
+            });
+            Ok(())
+        })
+    }
+
+    fn accept_friend_request(
+        &mut self,
+        params: social::AcceptFriendRequestParams,
+        _: social::AcceptFriendRequestResults,
+    ) -> Promise<(), Error> {
+        pry!(self.limiter.check(Some(&self.username)));
+        let other = pry!(pry!(pry!(params.get()).get_username()).to_str()).to_string();
+        let db = self.db.clone();
+        let presence = self.presence.clone();
+        let user = self.username.clone();
+        Promise::from_future(async move {
+            if !requests_to(&db, &user).await?.contains(&other) {
+                return Err(Error::failed(format!(
+                    "{other} has not asked to become friends"
+                )));
+            }
+            befriend(&db, &presence, &user, &other).await
+        })
+    }
+
+    fn remove_friend(
+        &mut self,
+        params: social::RemoveFriendParams,
+        _: social::RemoveFriendResults,
+    ) -> Promise<(), Error> {
+        pry!(self.limiter.check(Some(&self.username)));
+        let other = pry!(pry!(pry!(params.get()).get_username()).to_str()).to_string();
+        let db = self.db.clone();
+        let user = self.username.clone();
+        Promise::from_future(async move {
+            db.query(
+                "LET $a = type::thing('user', $user); LET $b = type::thing('user', $other); \
+                DELETE friend WHERE (in=$a AND out=$b) OR (in=$b AND out=$a); \
+                DELETE friend_request WHERE (in=$a AND out=$b) OR (in=$b AND out=$a)",
+            )
+            .bind(("user", user))
+            .bind(("other", other))
+            .await
+            .map_err(db_error)?;
+            Ok(())
+        })
+    }
+
+    fn friends(
+        &mut self,
+        _: social::FriendsParams,
+        mut results: social::FriendsResults,
+    ) -> Promise<(), Error> {
+        pry!(self.limiter.check(Some(&self.username)));
+        let db = self.db.clone();
+        let presence = self.presence.clone();
+        let user = self.username.clone();
+        Promise::from_future(async move {
+            let friends = friends_of(&db, &user).await?;
+            let presence = presence.borrow();
+            let mut list = results.get().init_friends(friends.len() as u32);
+            for (i, username) in friends.iter().enumerate() {
+                let mut friend = list.reborrow().get(i as u32);
+                friend.set_username(username.as_str().into());
+                friend.set_online(presence.is_online(username));
+            }
+            Ok(())
+        })
+    }
+
+    fn friend_requests(
+        &mut self,
+        _: social::FriendRequestsParams,
+        mut results: social::FriendRequestsResults,
+    ) -> Promise<(), Error> {
+        pry!(self.limiter.check(Some(&self.username)));
+        let db = self.db.clone();
+        let user = self.username.clone();
+        Promise::from_future(async move {
+            let requests = requests_to(&db, &user).await?;
+            let mut list = results.get().init_usernames(requests.len() as u32);
+            for (i, username) in requests.iter().enumerate() {
+                list.set(i as u32, username.as_str().into());
+            }
+            Ok(())
+        })
+    }
+}
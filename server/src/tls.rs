@@ -0,0 +1,44 @@
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::net::TcpStream;
+use tokio_rustls::server::TlsStream;
+use tokio_rustls::{rustls, TlsAcceptor};
+use tokio_util::either::Either;
+
+/// A connection from a client, encrypted when the server has a certificate.
+pub type Stream = Either<TcpStream, TlsStream<TcpStream>>;
+
+/// Load the certificate chain and private key the server proves its identity with, from PEM
+/// files.
+pub fn load_acceptor(certificate: &Path, private_key: &Path) -> io::Result<TlsAcceptor> {
+    let certificates = rustls_pemfile::certs(&mut BufReader::new(File::open(certificate)?))
+        .collect::<Result<Vec<_>, _>>()?;
+    if certificates.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("No certificate in {}", certificate.display()),
+        ));
+    }
+    let key = rustls_pemfile::private_key(&mut BufReader::new(File::open(private_key)?))?
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("No private key in {}", private_key.display()),
+            )
+        })?;
+    let config = rustls::ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certificates, key)
+        .map_err(io::Error::other)?;
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+/// Run the TLS handshake of a connection when the server has a certificate.
+pub async fn secure(stream: TcpStream, tls: Option<TlsAcceptor>) -> io::Result<Stream> {
+    Ok(match tls {
+        Some(acceptor) => Either::Right(acceptor.accept(stream).await?),
+        None => Either::Left(stream),
+    })
+}
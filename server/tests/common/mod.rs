@@ -0,0 +1,101 @@
+//! Runs the server binary for the tests and speaks the protocol to it as the clients do.
+
+// Each test file only uses part of these
+#![allow(dead_code)]
+
+use capnp_rpc::{rpc_twoparty_capnp, twoparty, RpcSystem};
+use fluffy_chess_protocol::fluffy_chess_capnp::{game_maker, handshake};
+use futures::AsyncReadExt;
+use std::net::{SocketAddr, TcpListener};
+use std::process::{Child, Command};
+use std::time::Duration;
+use tokio::net::TcpStream;
+
+/// The server process, killed when the test ends.
+pub struct Server {
+    process: Child,
+    pub tcp: SocketAddr,
+    pub websocket: SocketAddr,
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.process.kill();
+        let _ = self.process.wait();
+    }
+}
+
+fn free_address() -> SocketAddr {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+}
+
+/// Start the server with both listeners, and these arguments.
+pub fn start_server(args: &[&str]) -> Server {
+    let (tcp, websocket) = (free_address(), free_address());
+    let process = Command::new(env!("CARGO_BIN_EXE_server"))
+        .arg(tcp.to_string())
+        .arg("--websocket")
+        .arg(websocket.to_string())
+        .args(args)
+        .spawn()
+        .expect("server binary");
+    Server {
+        process,
+        tcp,
+        websocket,
+    }
+}
+
+/// Connect to a listener, waiting for the server to start.
+pub async fn connect(address: SocketAddr) -> TcpStream {
+    for _ in 0..50 {
+        if let Ok(stream) = TcpStream::connect(address).await {
+            return stream;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("The server doesn't accept connections on {address}");
+}
+
+/// Bootstrap the capability the server starts connections with, the handshake, over a
+/// connection. Old clients took it for another interface.
+pub fn bootstrap<T: capnp::capability::FromClientHook>(
+    stream: impl futures::AsyncRead + futures::AsyncWrite + 'static,
+) -> T {
+    let (reader, writer) = stream.split();
+    let network = twoparty::VatNetwork::new(
+        reader,
+        writer,
+        rpc_twoparty_capnp::Side::Client,
+        Default::default(),
+    );
+    let mut rpc_system = RpcSystem::new(Box::new(network), None);
+    let handshake = rpc_system.bootstrap(rpc_twoparty_capnp::Side::Server);
+    tokio::task::spawn_local(rpc_system);
+    handshake
+}
+
+pub async fn hello(
+    handshake: &handshake::Client,
+    version: u32,
+) -> capnp::Result<game_maker::Client> {
+    let mut request = handshake.hello_request();
+    request.get().set_version(version);
+    request.send().promise.await?.get()?.get_game_maker()
+}
+
+/// Number of games being played, asked through the game maker.
+pub async fn game_count(game_maker: &game_maker::Client) -> capnp::Result<u32> {
+    let directory = game_maker
+        .directory_request()
+        .send()
+        .promise
+        .await?
+        .get()?
+        .get_directory()?;
+    let games = directory.games_request().send().promise.await?;
+    Ok(games.get()?.get_games()?.len())
+}
@@ -0,0 +1,26 @@
+//! Runs the server and speaks to it the way clients from before the handshake did.
+
+mod common;
+
+use common::{bootstrap, connect, start_server};
+use fluffy_chess_protocol::fluffy_chess_capnp::game_maker;
+use tokio_util::compat::TokioAsyncReadCompatExt;
+
+#[tokio::test]
+async fn client_without_handshake_is_told_to_update() {
+    let server = start_server(&[]);
+    tokio::task::LocalSet::new()
+        .run_until(async move {
+            // These clients bootstrapped the game maker and looked for a game right away
+            let game_maker: game_maker::Client = bootstrap(connect(server.tcp).await.compat());
+            let mut request = game_maker.find_game_request();
+            request.get().init_game_config().set_user("outdated".into());
+            let err = request.send().promise.await.err().unwrap();
+            assert!(
+                err.to_string()
+                    .contains("protocol version mismatch, update the client"),
+                "{err}"
+            );
+        })
+        .await;
+}
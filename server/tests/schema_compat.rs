@@ -0,0 +1,283 @@
+//! Checks that the schema keeps the wire compatibility with its released version, following the
+//! evolution rules at the top of `schema/fluffy_chess.capnp`.
+
+use capnp::message::ReaderOptions;
+use capnp::schema_capnp::{code_generator_request, field, node, type_, value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+type Message = capnp::message::Reader<capnp::serialize::OwnedSegments>;
+
+/// Compile a schema into `dir`, returns the request the compiler gives to code generators.
+fn compile(schema: &Path, dir: &Path) -> Message {
+    std::fs::create_dir_all(dir).unwrap();
+    let request_path = dir.join("request.bin");
+    capnpc::CompilerCommand::new()
+        .src_prefix(schema.parent().unwrap())
+        .file(schema)
+        .output_path(dir)
+        .raw_code_generator_request_path(&request_path)
+        .run()
+        .expect("schema compiler command");
+    let file = std::fs::File::open(request_path).unwrap();
+    capnp::serialize::read_message(std::io::BufReader::new(file), ReaderOptions::new()).unwrap()
+}
+
+fn compile_schema(path: &str, name: &str) -> Message {
+    let schema = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(path);
+    compile(&schema, &Path::new(env!("CARGO_TARGET_TMPDIR")).join(name))
+}
+
+/// Compile a schema written by a test.
+fn compile_source(source: &str, name: &str) -> Message {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    std::fs::create_dir_all(&dir).unwrap();
+    let schema = dir.join("test.capnp");
+    std::fs::write(&schema, source).unwrap();
+    compile(&schema, &dir)
+}
+
+fn nodes(message: &Message) -> HashMap<u64, node::Reader> {
+    let request = message
+        .get_root::<code_generator_request::Reader>()
+        .unwrap();
+    request
+        .get_nodes()
+        .unwrap()
+        .iter()
+        .map(|node| (node.get_id(), node))
+        .collect()
+}
+
+fn text(reader: capnp::Result<capnp::text::Reader>) -> String {
+    reader.unwrap().to_str().unwrap().to_string()
+}
+
+fn protocol_version(message: &Message) -> u32 {
+    nodes(message)
+        .values()
+        .find_map(|node| match node.which().unwrap() {
+            node::Const(constant) if text(node.get_display_name()).ends_with("protocolVersion") => {
+                match constant.get_value().unwrap().which().unwrap() {
+                    value::Uint32(version) => Some(version),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .expect("protocolVersion constant")
+}
+
+/// Every way `new` breaks the wire compatibility with `old`.
+fn breaking_changes(old: &Message, new: &Message) -> Vec<String> {
+    let new_nodes = nodes(new);
+    let mut breaks = Vec::new();
+    for (id, old_node) in nodes(old) {
+        let name = text(old_node.get_display_name());
+        let Some(new_node) = new_nodes.get(&id) else {
+            breaks.push(format!("{name} was removed or renamed"));
+            continue;
+        };
+        match (old_node.which().unwrap(), new_node.which().unwrap()) {
+            (node::Struct(old), node::Struct(new)) => check_struct(&name, old, new, &mut breaks),
+            (node::Enum(old), node::Enum(new)) => {
+                // Enumerants are sent by their position
+                if new.get_enumerants().unwrap().len() < old.get_enumerants().unwrap().len() {
+                    breaks.push(format!("{name} lost enumerants"));
+                }
+            }
+            (node::Interface(old), node::Interface(new)) => {
+                check_interface(&name, old, new, &mut breaks)
+            }
+            // Constants, annotations and files are not sent
+            (node::Const(_), node::Const(_))
+            | (node::Annotation(_), node::Annotation(_))
+            | (node::File(()), node::File(())) => {}
+            _ => breaks.push(format!("{name} changed kind")),
+        }
+    }
+    breaks.sort();
+    breaks
+}
+
+/// Fields keep their ordinal, groups which have none keep their name.
+fn same_field(old: field::Reader, new: field::Reader) -> bool {
+    match (
+        old.get_ordinal().which().unwrap(),
+        new.get_ordinal().which().unwrap(),
+    ) {
+        (field::ordinal::Explicit(old), field::ordinal::Explicit(new)) => old == new,
+        (field::ordinal::Implicit(()), field::ordinal::Implicit(())) => {
+            text(old.get_name()) == text(new.get_name())
+        }
+        _ => false,
+    }
+}
+
+fn same_type(old: type_::Reader, new: type_::Reader) -> bool {
+    match (old.which().unwrap(), new.which().unwrap()) {
+        (type_::List(old), type_::List(new)) => same_type(
+            old.get_element_type().unwrap(),
+            new.get_element_type().unwrap(),
+        ),
+        (type_::Enum(old), type_::Enum(new)) => old.get_type_id() == new.get_type_id(),
+        (type_::Struct(old), type_::Struct(new)) => old.get_type_id() == new.get_type_id(),
+        (type_::Interface(old), type_::Interface(new)) => old.get_type_id() == new.get_type_id(),
+        (old, new) => std::mem::discriminant(&old) == std::mem::discriminant(&new),
+    }
+}
+
+fn check_struct(
+    name: &str,
+    old: node::struct_::Reader,
+    new: node::struct_::Reader,
+    breaks: &mut Vec<String>,
+) {
+    // Structs can only grow, old readers skip what they don't know
+    if new.get_data_word_count() < old.get_data_word_count()
+        || new.get_pointer_count() < old.get_pointer_count()
+    {
+        breaks.push(format!("{name} shrank"));
+    }
+    if old.get_discriminant_count() > 0
+        && new.get_discriminant_offset() != old.get_discriminant_offset()
+    {
+        breaks.push(format!("{name} moved its union discriminant"));
+    }
+
+    let new_fields = new.get_fields().unwrap();
+    for old_field in old.get_fields().unwrap() {
+        let field_name = format!("{name}.{}", text(old_field.get_name()));
+        let Some(new_field) = new_fields
+            .iter()
+            .find(|new_field| same_field(old_field, *new_field))
+        else {
+            breaks.push(format!("{field_name} was removed"));
+            continue;
+        };
+        if new_field.get_discriminant_value() != old_field.get_discriminant_value() {
+            breaks.push(format!("{field_name} moved in or out of a union"));
+        }
+        match (old_field.which().unwrap(), new_field.which().unwrap()) {
+            (field::Slot(old), field::Slot(new)) => {
+                if !same_type(old.get_type().unwrap(), new.get_type().unwrap()) {
+                    breaks.push(format!("{field_name} changed type"));
+                } else if old.get_offset() != new.get_offset() {
+                    breaks.push(format!("{field_name} moved"));
+                }
+            }
+            (field::Group(old), field::Group(new)) => {
+                if old.get_type_id() != new.get_type_id() {
+                    breaks.push(format!("{field_name} was renamed"));
+                }
+            }
+            _ => breaks.push(format!("{field_name} changed between a field and a group")),
+        }
+    }
+}
+
+fn check_interface(
+    name: &str,
+    old: node::interface::Reader,
+    new: node::interface::Reader,
+    breaks: &mut Vec<String>,
+) {
+    // Methods are called by their ordinal, their parameters and results are checked as structs
+    let new_methods = new.get_methods().unwrap();
+    for (i, old_method) in old.get_methods().unwrap().iter().enumerate() {
+        let method_name = format!("{name}.{}", text(old_method.get_name()));
+        if i as u32 >= new_methods.len() {
+            breaks.push(format!("{method_name} was removed"));
+            continue;
+        }
+        let new_method = new_methods.get(i as u32);
+        if new_method.get_param_struct_type() != old_method.get_param_struct_type()
+            || new_method.get_result_struct_type() != old_method.get_result_struct_type()
+        {
+            breaks.push(format!(
+                "{method_name} was renamed or changed its parameters or results"
+            ));
+        }
+    }
+}
+
+#[test]
+fn schema_is_compatible_with_released_version() {
+    let released = compile_schema("../schema/released/fluffy_chess.capnp", "released");
+    let current = compile_schema("../schema/fluffy_chess.capnp", "current");
+    assert_eq!(
+        protocol_version(&current),
+        protocol_version(&released),
+        "protocolVersion changed, replace schema/released/fluffy_chess.capnp with the new schema"
+    );
+    let breaks = breaking_changes(&released, &current);
+    assert!(
+        breaks.is_empty(),
+        "The schema breaks the wire compatibility with the released version:\n{}",
+        breaks.join("\n")
+    );
+}
+
+const OLD_SCHEMA: &str = "@0xd3a8b4f4e0c5a1b2;
+struct Square {
+    x @0 :UInt8;
+    y @1 :UInt8;
+}
+interface Board {
+    move @0 (square :Square);
+}
+";
+
+#[test]
+fn added_fields_and_methods_are_compatible() {
+    let old = compile_source(OLD_SCHEMA, "old_added");
+    let new = compile_source(
+        "@0xd3a8b4f4e0c5a1b2;
+        struct Square {
+            x @0 :UInt8;
+            y @1 :UInt8;
+            z @2 :UInt8;
+        }
+        interface Board {
+            move @0 (square :Square);
+            undo @1 ();
+        }",
+        "new_added",
+    );
+    assert_eq!(breaking_changes(&old, &new), Vec::<String>::new());
+}
+
+#[test]
+fn retyped_field_is_rejected() {
+    let old = compile_source(OLD_SCHEMA, "old_retyped");
+    let new = compile_source(
+        "@0xd3a8b4f4e0c5a1b2;
+        struct Square {
+            x @0 :UInt8;
+            y @1 :UInt32;
+        }
+        interface Board {
+            move @0 (square :Square);
+        }",
+        "new_retyped",
+    );
+    assert_eq!(
+        breaking_changes(&old, &new),
+        vec!["test.capnp:Square.y changed type".to_string()]
+    );
+}
+
+#[test]
+fn removed_method_is_rejected() {
+    let old = compile_source(OLD_SCHEMA, "old_removed");
+    let new = compile_source(
+        "@0xd3a8b4f4e0c5a1b2;
+        struct Square {
+            x @0 :UInt8;
+            y @1 :UInt8;
+        }
+        interface Board {}",
+        "new_removed",
+    );
+    assert!(breaking_changes(&old, &new).contains(&"test.capnp:Board.move was removed".to_string()));
+}
@@ -0,0 +1,105 @@
+//! Runs the server with a generated self-signed certificate and connects to it through TLS.
+
+mod common;
+
+use common::{bootstrap, connect, game_count, hello, start_server, Server};
+use fluffy_chess_protocol::fluffy_chess_capnp::PROTOCOL_VERSION;
+use fluffy_chess_protocol::websocket::WebSocketIo;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::net::TcpStream;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::rustls::pki_types::{CertificateDer, ServerName};
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+use tokio_rustls::TlsConnector;
+use tokio_util::compat::TokioAsyncReadCompatExt;
+
+/// Start the server with a new certificate for localhost, returns the certificate to trust.
+fn start_tls_server(name: &str) -> (Server, CertificateDer<'static>) {
+    let certificate = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    std::fs::create_dir_all(&dir).unwrap();
+    let (certificate_path, key_path) = (dir.join("certificate.pem"), dir.join("key.pem"));
+    std::fs::write(&certificate_path, certificate.serialize_pem().unwrap()).unwrap();
+    std::fs::write(&key_path, certificate.serialize_private_key_pem()).unwrap();
+
+    let server = start_server(&[
+        "--certificate",
+        certificate_path.to_str().unwrap(),
+        "--private-key",
+        key_path.to_str().unwrap(),
+    ]);
+    (
+        server,
+        CertificateDer::from(certificate.serialize_der().unwrap()),
+    )
+}
+
+/// Connect through TLS, trusting only `trusted`.
+async fn connect_tls(
+    address: SocketAddr,
+    trusted: Option<CertificateDer<'static>>,
+) -> std::io::Result<TlsStream<TcpStream>> {
+    let mut roots = RootCertStore::empty();
+    if let Some(certificate) = trusted {
+        roots.add(certificate).unwrap();
+    }
+    let config = ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    let stream = connect(address).await;
+    TlsConnector::from(Arc::new(config))
+        .connect(ServerName::try_from("localhost").unwrap(), stream)
+        .await
+}
+
+#[tokio::test]
+async fn rpc_over_tls() {
+    let (server, certificate) = start_tls_server("rpc_over_tls");
+    tokio::task::LocalSet::new()
+        .run_until(async move {
+            let stream = connect_tls(server.tcp, Some(certificate)).await.unwrap();
+            let game_maker = hello(&bootstrap(stream.compat()), PROTOCOL_VERSION)
+                .await
+                .unwrap();
+            assert_eq!(game_count(&game_maker).await.unwrap(), 0);
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn rpc_over_secure_websocket() {
+    let (server, certificate) = start_tls_server("rpc_over_secure_websocket");
+    tokio::task::LocalSet::new()
+        .run_until(async move {
+            let stream = connect_tls(server.websocket, Some(certificate))
+                .await
+                .unwrap();
+            let (websocket, _) = tokio_tungstenite::client_async("wss://localhost", stream)
+                .await
+                .unwrap();
+            let game_maker = hello(&bootstrap(WebSocketIo::new(websocket)), PROTOCOL_VERSION)
+                .await
+                .unwrap();
+            assert_eq!(game_count(&game_maker).await.unwrap(), 0);
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn untrusted_certificate_is_refused() {
+    let (server, _) = start_tls_server("untrusted_certificate_is_refused");
+    assert!(connect_tls(server.tcp, None).await.is_err());
+}
+
+#[tokio::test]
+async fn plaintext_client_is_refused() {
+    let (server, _) = start_tls_server("plaintext_client_is_refused");
+    tokio::task::LocalSet::new()
+        .run_until(async move {
+            let handshake = bootstrap(connect(server.tcp).await.compat());
+            assert!(hello(&handshake, PROTOCOL_VERSION).await.is_err());
+        })
+        .await;
+}
@@ -0,0 +1,63 @@
+//! Runs the server with a WebSocket listener and speaks the protocol to it the way a browser
+//! client does.
+
+mod common;
+
+use common::{bootstrap, connect, game_count, hello, start_server};
+use fluffy_chess_protocol::fluffy_chess_capnp::{handshake, PROTOCOL_VERSION};
+use fluffy_chess_protocol::websocket::WebSocketIo;
+use std::net::SocketAddr;
+
+async fn connect_websocket(address: SocketAddr) -> handshake::Client {
+    let stream = connect(address).await;
+    let (websocket, _) = tokio_tungstenite::client_async(format!("ws://{address}"), stream)
+        .await
+        .unwrap();
+    bootstrap(WebSocketIo::new(websocket))
+}
+
+#[tokio::test]
+async fn rpc_over_websocket() {
+    let server = start_server(&[]);
+    tokio::task::LocalSet::new()
+        .run_until(async move {
+            let handshake = connect_websocket(server.websocket).await;
+            let game_maker = hello(&handshake, PROTOCOL_VERSION).await.unwrap();
+            assert_eq!(game_count(&game_maker).await.unwrap(), 0);
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn outdated_client_is_rejected_over_websocket() {
+    let server = start_server(&[]);
+    tokio::task::LocalSet::new()
+        .run_until(async move {
+            let handshake = connect_websocket(server.websocket).await;
+            let err = hello(&handshake, PROTOCOL_VERSION - 1).await.err().unwrap();
+            assert!(err.to_string().contains("update the client"), "{err}");
+        })
+        .await;
+}
+
+#[tokio::test]
+async fn tcp_and_websocket_reach_the_same_server() {
+    let server = start_server(&[]);
+    tokio::task::LocalSet::new()
+        .run_until(async move {
+            let tcp = bootstrap(tokio_util::compat::TokioAsyncReadCompatExt::compat(
+                connect(server.tcp).await,
+            ));
+            let websocket = connect_websocket(server.websocket).await;
+            let (tcp, websocket) = futures::try_join!(
+                hello(&tcp, PROTOCOL_VERSION),
+                hello(&websocket, PROTOCOL_VERSION)
+            )
+            .unwrap();
+            assert_eq!(
+                game_count(&tcp).await.unwrap(),
+                game_count(&websocket).await.unwrap()
+            );
+        })
+        .await;
+}